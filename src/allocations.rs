@@ -0,0 +1,135 @@
+// Counts heap allocations, so the notes about the heap can show what really
+// happens, e.g. that pushing onto a full Vec reallocates it.
+//
+// Counting only happens when Counting is the program's global allocator.
+// The binary installs it when built with the count-allocations feature:
+//
+//     cargo run --features count-allocations -- run intro_1::vectors_and_hashmaps
+//
+// and then the run summary gets allocation columns. Tests can install it
+// in their own crate the same way main.rs does.
+//
+// The counts are kept per thread, so a measurement is not thrown off by
+// other threads (like other tests) allocating at the same time.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::hint;
+use std::ops::Sub;
+
+/// A global allocator that hands everything to the system allocator and
+/// counts what passes through it.
+pub struct Counting;
+
+thread_local! {
+    // const, so reading these never allocates (which would recurse).
+    static COUNTS: Cell<Stats> = const { Cell::new(Stats::ZERO) };
+}
+
+fn count<F: FnOnce(&mut Stats)>(change: F) {
+    // try_with, because allocations still happen while a thread's locals are
+    // being torn down.
+    let _ = COUNTS.try_with(|counts| {
+        let mut stats = counts.get();
+        change(&mut stats);
+        counts.set(stats);
+    });
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count(|stats| {
+            stats.allocations += 1;
+            stats.bytes += layout.size() as u64;
+        });
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count(|stats| {
+            stats.allocations += 1;
+            stats.bytes += layout.size() as u64;
+        });
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count(|stats| {
+            stats.reallocations += 1;
+            stats.bytes += new_size.saturating_sub(layout.size()) as u64;
+        });
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        count(|stats| stats.deallocations += 1);
+        System.dealloc(ptr, layout)
+    }
+}
+
+/// What was allocated on one thread over some stretch of time.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub allocations: u64,
+    pub reallocations: u64,
+    pub deallocations: u64,
+    /// Bytes asked for by allocations, plus what reallocations grew by.
+    pub bytes: u64,
+}
+
+impl Stats {
+    const ZERO: Stats = Stats {
+        allocations: 0,
+        reallocations: 0,
+        deallocations: 0,
+        bytes: 0,
+    };
+}
+
+impl Sub for Stats {
+    type Output = Stats;
+
+    fn sub(self, earlier: Stats) -> Stats {
+        Stats {
+            allocations: self.allocations - earlier.allocations,
+            reallocations: self.reallocations - earlier.reallocations,
+            deallocations: self.deallocations - earlier.deallocations,
+            bytes: self.bytes - earlier.bytes,
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} allocation(s), {} reallocation(s), {} deallocation(s), {} byte(s)",
+            self.allocations, self.reallocations, self.deallocations, self.bytes
+        )
+    }
+}
+
+/// Everything counted on this thread so far.
+pub fn current() -> Stats {
+    COUNTS.try_with(Cell::get).unwrap_or_default()
+}
+
+/// Run `f` and count what it allocated on this thread. The stats are all
+/// zero when Counting is not the global allocator.
+pub fn measure<F, R>(f: F) -> (R, Stats)
+where
+    F: FnOnce() -> R,
+{
+    let before = current();
+    let result = f();
+    (result, current() - before)
+}
+
+/// Whether Counting is the global allocator, i.e. whether measure() sees
+/// anything.
+pub fn is_counting() -> bool {
+    // black_box keeps the compiler from optimizing the allocation away.
+    let (_, stats) = measure(|| hint::black_box(Box::new(0u8)));
+    stats.allocations > 0
+}
@@ -0,0 +1,51 @@
+// The annotated view of a lesson: its code and comments, with what each
+// outln! printed shown right under the line that printed it.
+//
+//  97 |     outln!(out, "{:?}", the_string);
+//     | > "Hello "
+
+use lesson::Lesson;
+use output::Printed;
+
+/// Render `lesson`'s source with the lines it `printed` interleaved.
+pub fn render(lesson: &dyn Lesson, printed: &[Printed]) -> String {
+    let mut text = format!("== {}: {} ==\n", lesson.full_name(), lesson.title());
+
+    let (file, function) = match (lesson.source(), lesson.function()) {
+        (Some(source), Some(function)) => (source.file, function),
+        _ => {
+            text.push_str("(no source available, showing output only)\n");
+            for line in printed {
+                text.push_str(&line.text);
+                text.push('\n');
+            }
+            return text;
+        }
+    };
+
+    let last_line = function.first_line as usize + function.lines.len();
+    let width = last_line.to_string().len();
+    let from_here = |line: &Printed| line.file == file && function.contains_line(line.line);
+
+    for (number, code) in function.numbered() {
+        let row = format!("{:>width$} | {}", number, code, width = width);
+        text.push_str(row.trim_end());
+        text.push('\n');
+        for line in printed.iter().filter(|p| from_here(p) && p.line == number) {
+            // pretty printed output ({:#?}) spans several lines.
+            for part in line.text.lines() {
+                text.push_str(&format!("{:>width$} | > {}\n", "", part, width = width));
+            }
+        }
+    }
+
+    // output from code outside the lesson, like a helper in another module.
+    let elsewhere: Vec<&Printed> = printed.iter().filter(|p| !from_here(p)).collect();
+    if !elsewhere.is_empty() {
+        text.push_str("printed from outside this lesson:\n");
+        for line in elsewhere {
+            text.push_str(&format!("  {}:{} > {}\n", line.file, line.line, line.text));
+        }
+    }
+    text
+}
@@ -0,0 +1,95 @@
+// Area arithmetic that cannot overflow by accident. `width * height` on two
+// u32s panics in debug builds and wraps around in release builds once the
+// area is bigger than u32::MAX (a 70000x70000 rectangle is enough), and
+// `area as u32` quietly turns NaN into 0 and anything too big into
+// u32::MAX. The functions here say what happens instead:
+//
+// - checked_* return an AreaError when the answer does not fit,
+// - saturating_* stop at the biggest value the type can hold,
+// - widening_* return a type big enough that it always fits.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AreaError {
+    /// width * height is too big for the type asked for.
+    Overflow { width: u64, height: u64 },
+    /// The area came out as infinity or NaN.
+    NotFinite(f64),
+    /// A negative area, which no shape can have.
+    Negative(f64),
+    /// A float area too big for the integer type asked for.
+    TooLarge(f64),
+}
+
+impl fmt::Display for AreaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AreaError::Overflow { width, height } => {
+                write!(f, "the area of {}x{} overflows", width, height)
+            }
+            AreaError::NotFinite(area) => write!(f, "the area is {}", area),
+            AreaError::Negative(area) => write!(f, "the area {} is negative", area),
+            AreaError::TooLarge(area) => write!(f, "the area {} is too large", area),
+        }
+    }
+}
+
+pub fn checked(width: u32, height: u32) -> Result<u32, AreaError> {
+    width.checked_mul(height).ok_or(AreaError::Overflow {
+        width: u64::from(width),
+        height: u64::from(height),
+    })
+}
+
+pub fn saturating(width: u32, height: u32) -> u32 {
+    width.saturating_mul(height)
+}
+
+/// Always fits: the product of two u32s is less than 2^64.
+pub fn widening(width: u32, height: u32) -> u64 {
+    u64::from(width) * u64::from(height)
+}
+
+pub fn checked_u64(width: u64, height: u64) -> Result<u64, AreaError> {
+    width.checked_mul(height).ok_or(AreaError::Overflow { width, height })
+}
+
+/// Always fits: the product of two u64s is less than 2^128.
+pub fn widening_u64(width: u64, height: u64) -> u128 {
+    u128::from(width) * u128::from(height)
+}
+
+/// A float area that is a real number, i.e. not infinite (from dimensions
+/// too big for f64) or NaN, and not negative.
+pub fn finite(area: f64) -> Result<f64, AreaError> {
+    if !area.is_finite() {
+        Err(AreaError::NotFinite(area))
+    } else if area < 0.0 {
+        Err(AreaError::Negative(area))
+    } else {
+        Ok(area)
+    }
+}
+
+/// A float area as a whole number, dropping the fraction like `as` does,
+/// but with an error where `as` would quietly give a wrong answer.
+pub fn checked_to_u32(area: f64) -> Result<u32, AreaError> {
+    let area = finite(area)?;
+    if area >= f64::from(u32::MAX) + 1.0 {
+        Err(AreaError::TooLarge(area))
+    } else {
+        Ok(area as u32)
+    }
+}
+
+/// A float area as a whole number, with anything too big becoming u32::MAX
+/// and anything that is not an area (NaN or negative) becoming 0.
+pub fn saturating_to_u32(area: f64) -> u32 {
+    match checked_to_u32(area) {
+        Ok(area) => area,
+        Err(AreaError::TooLarge(_)) => u32::MAX,
+        Err(AreaError::NotFinite(area)) if area > 0.0 => u32::MAX,
+        Err(_) => 0,
+    }
+}
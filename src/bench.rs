@@ -0,0 +1,186 @@
+// A small benchmark harness using only std, to put numbers on what the
+// notes say about the three ways of getting polymorphism:
+//
+// - an enum with a match (AnyShape, like Shape in _enums_and_options),
+// - trait objects, Box<dyn Shape>, with a virtual call per shape (_traits),
+// - generics, where the compiler writes a copy of the code for each type
+//   (Shape<T> from generic_types), so there is no dispatch at all.
+//
+// Each benchmark runs a few times to warm up (caches, branch predictors,
+// CPU frequency) and then `iterations` times for real. The median is the
+// number to look at; the standard deviation says how noisy the run was.
+// Numbers from a debug build say little, so run this with --release.
+
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use generics;
+use shapes::{AnyShape, Circle, Rectangle, Shape};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Options {
+    /// Runs thrown away before measuring.
+    pub warmup: u32,
+    /// Runs measured.
+    pub iterations: u32,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            warmup: 3,
+            iterations: 15,
+        }
+    }
+}
+
+/// How long each measured run of one benchmark took.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub name: String,
+    /// How many items each run went through, for the time per item.
+    pub items: usize,
+    pub samples: Vec<Duration>,
+}
+
+impl Measurement {
+    pub fn median(&self) -> Duration {
+        let mut sorted = self.samples.clone();
+        sorted.sort();
+        match sorted.len() {
+            0 => Duration::from_secs(0),
+            n if n % 2 == 1 => sorted[n / 2],
+            n => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+        }
+    }
+
+    pub fn mean(&self) -> Duration {
+        if self.samples.is_empty() {
+            return Duration::from_secs(0);
+        }
+        self.samples.iter().sum::<Duration>() / self.samples.len() as u32
+    }
+
+    /// The sample standard deviation, 0 with fewer than two samples.
+    pub fn stddev(&self) -> Duration {
+        if self.samples.len() < 2 {
+            return Duration::from_secs(0);
+        }
+        let mean = self.mean().as_secs_f64();
+        let squares: f64 = self.samples.iter().map(|sample| (sample.as_secs_f64() - mean).powi(2)).sum();
+        Duration::from_secs_f64((squares / (self.samples.len() - 1) as f64).sqrt())
+    }
+
+    /// The median time for one item, in nanoseconds.
+    pub fn nanos_per_item(&self) -> f64 {
+        self.median().as_secs_f64() * 1e9 / self.items.max(1) as f64
+    }
+}
+
+/// Time `f`, which goes through `items` items each time it is called.
+/// Whatever `f` returns is passed to black_box, so the compiler cannot
+/// skip working it out.
+pub fn measure<R, F: FnMut() -> R>(name: &str, items: usize, options: Options, mut f: F) -> Measurement {
+    for _ in 0..options.warmup {
+        black_box(f());
+    }
+    let samples = (0..options.iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Measurement {
+        name: name.to_string(),
+        items,
+        samples,
+    }
+}
+
+/// Measurements side by side, compared with the fastest median.
+pub struct Table<'a>(pub &'a [Measurement]);
+
+impl<'a> fmt::Display for Table<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = self.0.iter().map(|m| m.name.len()).max().unwrap_or(0).max(9);
+        let fastest = self.0.iter().map(Measurement::median).min().unwrap_or_default();
+        writeln!(
+            f,
+            "{:<width$} {:>12} {:>12} {:>12} {:>9} {:>8}",
+            "benchmark",
+            "median",
+            "mean",
+            "stddev",
+            "ns/item",
+            "vs best",
+            width = width
+        )?;
+        for m in self.0 {
+            let relative = m.median().as_secs_f64() / fastest.as_secs_f64();
+            writeln!(
+                f,
+                "{:<width$} {:>12} {:>12} {:>12} {:>9.2} {:>7.2}x",
+                m.name,
+                format!("{:.3?}", m.median()),
+                format!("{:.3?}", m.mean()),
+                format!("{:.3?}", m.stddev()),
+                m.nanos_per_item(),
+                if relative.is_finite() { relative } else { 1.0 },
+                width = width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Sum the areas of `count` shapes, half rectangles and half circles, with
+/// each kind of dispatch.
+pub fn dispatch(count: usize, options: Options) -> Vec<Measurement> {
+    // sizes from a simple generator, so the compiler cannot work the
+    // answers out ahead of time.
+    let mut seed = 0x2545_f491_4f6c_dd1du64;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        1.0 + (seed % 1000) as f64 / 10.0
+    };
+    let mut rectangles = Vec::with_capacity(count / 2 + 1);
+    let mut circles = Vec::with_capacity(count / 2 + 1);
+    let mut mixed: Vec<AnyShape> = Vec::with_capacity(count);
+    for i in 0..count {
+        if i % 2 == 0 {
+            let rectangle = Rectangle::new(next(), next());
+            rectangles.push(generics::Rectangle {
+                x: rectangle.width,
+                y: rectangle.height,
+            });
+            mixed.push(rectangle.into());
+        } else {
+            let circle = Circle::new(next());
+            circles.push(generics::Circle { radius: circle.radius });
+            mixed.push(circle.into());
+        }
+    }
+    let boxed: Vec<Box<dyn Shape>> = mixed.iter().map(|&shape| shape.boxed()).collect();
+
+    // generic code only takes one type at a time, so the generic version
+    // sums the rectangles and the circles separately.
+    fn total<S: generics::Shape<f64>>(shapes: &[S]) -> f64 {
+        shapes.iter().map(|shape| shape.area()).sum()
+    }
+
+    vec![
+        measure("enum (match)", count, options, || {
+            black_box(&mixed).iter().map(|shape| shape.area()).sum::<f64>()
+        }),
+        measure("trait object (dyn)", count, options, || {
+            black_box(&boxed).iter().map(|shape| shape.area()).sum::<f64>()
+        }),
+        measure("generic (static)", count, options, || {
+            total(black_box(&rectangles)) + total(black_box(&circles))
+        }),
+    ]
+}
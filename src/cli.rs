@@ -0,0 +1,570 @@
+// Command-line handling. Instead of commenting and uncommenting calls in
+// main(), the lessons to run are picked with arguments:
+//
+//     rust_playground list
+//     rust_playground run intro_1::ownership
+//     rust_playground run --all
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str;
+
+use annotate;
+use bench;
+use exercises;
+use export::{self, Format};
+use lesson::{Lesson, Registry};
+use menu;
+use output::Output;
+use progress::{self, Progress};
+use quiz::{self, Score};
+use runner::{self, Summary};
+use shapes::{self, ascii, text, Shape};
+use snapshot::{self, Mode};
+
+/// Snapshots are looked up relative to where the program is run from,
+/// which is the crate root when using `cargo run`.
+const SNAPSHOT_DIR: &str = "snapshots";
+
+/// Default directory for `export`.
+const EXPORT_DIR: &str = "export";
+
+const USAGE: &str = "usage: rust_playground <command>
+
+commands:
+    list                  list every lesson
+    list --tag <tag>      list the lessons covering a topic, e.g. ownership
+    run <lesson>...       run the named lessons, e.g. intro_1::ownership
+    run --all             run every lesson in order
+    menu                  browse and run lessons interactively
+    quiz [<lesson>...]    answer questions about what the lessons print
+    progress              show which lessons you have run and quizzes passed
+    shapes <file> [--svg <out>] [--ascii <scale> [--outline]]
+                          read shapes (like `rect 30 20`) from a file and show
+                          their areas and perimeters, and draw them to <out>
+                          or in the terminal, <scale> units per character
+    bench [--shapes <n>] [--iterations <n>]
+                          time summing the areas of <n> shapes (default
+                          1000000) with an enum, trait objects and generics
+    exercise next         check the exercises and show the first unsolved one
+    exercise list         show every exercise and whether it is solved
+    run --annotated <lesson>...
+                          show each lesson's code with what every line printed
+    export [--html] [--out <dir>]
+                          write each module's notes and output to <dir>
+                          (default: export/) as Markdown, or HTML
+    snapshot              compare lesson output with the files in snapshots/
+    snapshot --update     rewrite the snapshots with the current output
+    help                  show this message";
+
+/// What the user asked for on the command line.
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    List { tag: Option<String> },
+    Run { names: Vec<String>, annotated: bool },
+    RunAll { annotated: bool },
+    Menu,
+    Quiz(Vec<String>),
+    Progress,
+    Shapes {
+        path: String,
+        svg: Option<String>,
+        ascii: Option<f64>,
+        outline: bool,
+    },
+    Bench { shapes: usize, iterations: u32 },
+    ExerciseNext,
+    ExerciseList,
+    Export { format: Format, dir: String },
+    Snapshot { update: bool },
+    Help,
+}
+
+/// Everything that can go wrong while reading the command line.
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    MissingCommand,
+    UnknownCommand(String),
+    UnexpectedArgument(String),
+    MissingValue(&'static str),
+    MissingLesson,
+    UnknownLesson {
+        name: String,
+        suggestions: Vec<String>,
+    },
+    Io(String),
+    SnapshotsChanged(usize),
+    LessonsFailed(usize),
+}
+
+impl Error {
+    /// 1 when something we ran failed, 2 when we could not tell what to run.
+    pub fn exit_code(&self) -> i32 {
+        match *self {
+            Error::SnapshotsChanged(_) | Error::LessonsFailed(_) => 1,
+            _ => 2,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::MissingCommand => write!(f, "no command given\n\n{}", USAGE),
+            Error::UnknownCommand(ref command) => {
+                write!(f, "unknown command `{}`\n\n{}", command, USAGE)
+            }
+            Error::UnexpectedArgument(ref arg) => write!(f, "unexpected argument `{}`", arg),
+            Error::MissingValue(flag) => write!(f, "`{}` needs a value", flag),
+            Error::MissingLesson => write!(
+                f,
+                "`run` needs a lesson name or `--all` (see `rust_playground list`)"
+            ),
+            Error::UnknownLesson {
+                ref name,
+                ref suggestions,
+            } => {
+                write!(f, "unknown lesson `{}`", name)?;
+                if !suggestions.is_empty() {
+                    write!(f, " (did you mean {}?)", suggestions.join(", "))?;
+                }
+                write!(f, "\nrun `rust_playground list` to see every lesson")
+            }
+            Error::Io(ref message) => write!(f, "{}", message),
+            Error::SnapshotsChanged(count) => write!(
+                f,
+                "{} lesson(s) no longer match their snapshots (use `snapshot --update` if that is expected)",
+                count
+            ),
+            Error::LessonsFailed(count) => write!(f, "{} lesson(s) panicked", count),
+        }
+    }
+}
+
+/// Turn the arguments (without the program name) into a Command.
+pub fn parse<I>(args: I) -> Result<Command, Error>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+    let command = match args.next() {
+        Some(command) => command,
+        None => return Err(Error::MissingCommand),
+    };
+
+    match command.as_str() {
+        "list" => {
+            let mut tag = None;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--tag" => match args.next() {
+                        Some(value) => tag = Some(value),
+                        None => return Err(Error::MissingValue("--tag")),
+                    },
+                    _ => return Err(Error::UnexpectedArgument(arg)),
+                }
+            }
+            Ok(Command::List { tag })
+        }
+        "run" => {
+            let mut all = false;
+            let mut annotated = false;
+            let mut names = Vec::new();
+            for arg in args {
+                match arg.as_str() {
+                    "--all" => all = true,
+                    "--annotated" => annotated = true,
+                    _ if arg.starts_with("--") => return Err(Error::UnexpectedArgument(arg)),
+                    _ => names.push(arg),
+                }
+            }
+            match (all, names.is_empty()) {
+                (true, true) => Ok(Command::RunAll { annotated }),
+                (true, false) => Err(Error::UnexpectedArgument(names.remove(0))),
+                (false, true) => Err(Error::MissingLesson),
+                (false, false) => Ok(Command::Run { names, annotated }),
+            }
+        }
+        "menu" => match args.next() {
+            Some(arg) => Err(Error::UnexpectedArgument(arg)),
+            None => Ok(Command::Menu),
+        },
+        "quiz" => Ok(Command::Quiz(args.collect())),
+        "progress" => match args.next() {
+            Some(arg) => Err(Error::UnexpectedArgument(arg)),
+            None => Ok(Command::Progress),
+        },
+        "shapes" => {
+            let mut path = None;
+            let mut svg = None;
+            let mut ascii = None;
+            let mut outline = false;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--svg" => match args.next() {
+                        Some(value) => svg = Some(value),
+                        None => return Err(Error::MissingValue("--svg")),
+                    },
+                    "--ascii" => match args.next() {
+                        Some(value) => match value.parse::<f64>() {
+                            Ok(scale) if scale > 0.0 && scale.is_finite() => ascii = Some(scale),
+                            _ => return Err(Error::UnexpectedArgument(value)),
+                        },
+                        None => return Err(Error::MissingValue("--ascii")),
+                    },
+                    "--outline" => outline = true,
+                    _ if path.is_none() && !arg.starts_with("--") => path = Some(arg),
+                    _ => return Err(Error::UnexpectedArgument(arg)),
+                }
+            }
+            if outline && ascii.is_none() {
+                return Err(Error::UnexpectedArgument(String::from("--outline")));
+            }
+            match path {
+                Some(path) => Ok(Command::Shapes {
+                    path,
+                    svg,
+                    ascii,
+                    outline,
+                }),
+                None => Err(Error::MissingValue("shapes")),
+            }
+        }
+        "bench" => {
+            let mut shapes = 1_000_000;
+            let mut iterations = bench::Options::default().iterations;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--shapes" => shapes = count(args.next(), "--shapes")?,
+                    "--iterations" => iterations = count(args.next(), "--iterations")?,
+                    _ => return Err(Error::UnexpectedArgument(arg)),
+                }
+            }
+            Ok(Command::Bench { shapes, iterations })
+        }
+        "exercise" => {
+            let command = match args.next() {
+                Some(ref arg) if arg == "next" => Command::ExerciseNext,
+                Some(ref arg) if arg == "list" => Command::ExerciseList,
+                Some(arg) => return Err(Error::UnexpectedArgument(arg)),
+                None => return Err(Error::MissingValue("exercise")),
+            };
+            match args.next() {
+                Some(arg) => Err(Error::UnexpectedArgument(arg)),
+                None => Ok(command),
+            }
+        }
+        "export" => {
+            let mut format = Format::Markdown;
+            let mut dir = String::from(EXPORT_DIR);
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--html" => format = Format::Html,
+                    "--out" => match args.next() {
+                        Some(value) => dir = value,
+                        None => return Err(Error::MissingValue("--out")),
+                    },
+                    _ => return Err(Error::UnexpectedArgument(arg)),
+                }
+            }
+            Ok(Command::Export { format, dir })
+        }
+        "snapshot" => match args.next() {
+            None => Ok(Command::Snapshot { update: false }),
+            Some(ref arg) if arg == "--update" => match args.next() {
+                Some(arg) => Err(Error::UnexpectedArgument(arg)),
+                None => Ok(Command::Snapshot { update: true }),
+            },
+            Some(arg) => Err(Error::UnexpectedArgument(arg)),
+        },
+        "help" | "--help" | "-h" => Ok(Command::Help),
+        _ => Err(Error::UnknownCommand(command)),
+    }
+}
+
+/// A flag's value that must be a whole number above 0.
+fn count<T: str::FromStr + PartialOrd + Default>(value: Option<String>, flag: &'static str) -> Result<T, Error> {
+    let value = value.ok_or(Error::MissingValue(flag))?;
+    match value.parse() {
+        Ok(n) if n > T::default() => Ok(n),
+        _ => Err(Error::UnexpectedArgument(value)),
+    }
+}
+
+/// Parse the arguments and do what they ask.
+pub fn run<I>(args: I) -> Result<(), Error>
+where
+    I: IntoIterator<Item = String>,
+{
+    let registry = Registry::builtin();
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+    let out = Output::new(&mut stdout);
+    match parse(args)? {
+        Command::List { tag } => {
+            for lesson in registry.iter() {
+                if let Some(ref tag) = tag {
+                    if !lesson.has_tag(tag) {
+                        continue;
+                    }
+                }
+                outln!(
+                    out,
+                    "{:<40} {} [{}]",
+                    lesson.full_name(),
+                    lesson.title(),
+                    lesson.tags().join(", ")
+                );
+            }
+        }
+        Command::Run { names, annotated } => {
+            let to_run = find_all(&registry, names)?;
+            run_lessons(to_run, &out, annotated)?;
+        }
+        Command::RunAll { annotated } => run_lessons(registry.iter(), &out, annotated)?,
+        Command::Menu => {
+            // the menu writes to stdout itself, so let go of our lock first.
+            drop(out);
+            drop(stdout);
+            let stdin = io::stdin();
+            let stdout = io::stdout();
+            let ran = menu::run(&registry, &mut stdin.lock(), &mut stdout.lock())
+                .map_err(|err| Error::Io(format!("menu failed: {}", err)))?;
+            let now = progress::now();
+            update_progress(|progress| {
+                for lesson in &ran {
+                    progress.record_run(lesson, now);
+                }
+            });
+        }
+        Command::Quiz(names) => {
+            let lessons = if names.is_empty() {
+                registry.iter().collect()
+            } else {
+                find_all(&registry, names)?
+            };
+            drop(out);
+            drop(stdout);
+            let stdin = io::stdin();
+            let stdout = io::stdout();
+            let (mut input, mut screen) = (stdin.lock(), stdout.lock());
+
+            let mut total = Score::default();
+            let mut results = Vec::new();
+            let mut stopped = false;
+            for lesson in lessons {
+                let score = match quiz::ask(lesson, &mut input, &mut screen)
+                    .map_err(|err| Error::Io(format!("quiz failed: {}", err)))?
+                {
+                    Some(score) => score,
+                    // out of input: the lessons answered so far still count.
+                    None => {
+                        stopped = true;
+                        break;
+                    }
+                };
+                total.correct += score.correct;
+                total.asked += score.asked;
+                if score.asked > 0 {
+                    results.push((lesson.full_name(), score));
+                }
+            }
+            let out = Output::new(&mut screen);
+            if stopped {
+                outln!(out, "no more answers, stopping the quiz");
+            }
+            outln!(out, "total score: {}/{}", total.correct, total.asked);
+
+            let now = progress::now();
+            update_progress(|progress| {
+                for (lesson, score) in results {
+                    progress.record_quiz(&lesson, score, now);
+                }
+            });
+        }
+        Command::Progress => {
+            let path = progress::default_path().ok_or_else(|| {
+                Error::Io(format!(
+                    "could not find a data directory to keep progress in, set {}",
+                    progress::DIR_VARIABLE
+                ))
+            })?;
+            let progress = Progress::load(&path)
+                .map_err(|err| Error::Io(format!("could not read {}: {}", path.display(), err)))?;
+            outln!(out, "progress file: {}", path.display());
+            outln!(out, "{}", progress.report(&registry).trim_end());
+        }
+        Command::Shapes {
+            path,
+            svg,
+            ascii,
+            outline,
+        } => {
+            let text = fs::read_to_string(&path)
+                .map_err(|err| Error::Io(format!("could not read {}: {}", path, err)))?;
+            let shapes = text::parse(&text).map_err(|err| Error::Io(format!("{}: {}", path, err)))?;
+            let width = shapes.iter().map(|shape| shape.to_string().len()).max().unwrap_or(0).max(5);
+            outln!(out, "{:<width$} {:>14} {:>14}", "shape", "area", "perimeter", width = width);
+            let mut total = 0.0;
+            for shape in &shapes {
+                let area = match shape.checked_area() {
+                    Ok(area) => {
+                        total += area;
+                        format!("{:.2}", area)
+                    }
+                    Err(err) => err.to_string(),
+                };
+                outln!(
+                    out,
+                    "{:<width$} {:>14} {:>14.2}",
+                    shape.to_string(),
+                    area,
+                    shape.perimeter(),
+                    width = width
+                );
+            }
+            outln!(out, "{} shape(s), total area {:.2}", shapes.len(), total);
+            if let Some(svg) = svg {
+                fs::write(&svg, shapes::svg::render(&shapes))
+                    .map_err(|err| Error::Io(format!("could not write {}: {}", svg, err)))?;
+                outln!(out, "wrote {}", svg);
+            }
+            if let Some(scale) = ascii {
+                let mode = if outline { ascii::Mode::Outline } else { ascii::Mode::Filled };
+                let drawing = ascii::render(&shapes, scale, mode)
+                    .map_err(|err| Error::Io(format!("{}: {}", path, err)))?;
+                outln!(out);
+                outln!(out, "{}", drawing.trim_end());
+            }
+        }
+        Command::Bench { shapes, iterations } => {
+            if cfg!(debug_assertions) {
+                outln!(out, "(a debug build: run with --release for meaningful numbers)");
+            }
+            let options = bench::Options {
+                iterations,
+                ..bench::Options::default()
+            };
+            outln!(out, "summing the areas of {} shapes, {} runs each:", shapes, iterations);
+            let measurements = bench::dispatch(shapes, options);
+            outln!(out, "{}", bench::Table(&measurements).to_string().trim_end());
+        }
+        Command::ExerciseNext => {
+            let exercises = exercises::builtin();
+            match exercises::next(&exercises) {
+                Some((exercise, status)) => {
+                    outln!(out, "{} (practice for {}): {}", exercise.name, exercise.lesson, status);
+                    outln!(
+                        out,
+                        "edit {} and run `cargo run -- exercise next` again",
+                        exercise.file
+                    );
+                    outln!(out, "hint: {}", exercise.hint);
+                }
+                None => outln!(out, "every exercise is solved!"),
+            }
+        }
+        Command::ExerciseList => {
+            for exercise in exercises::builtin() {
+                let status = exercises::check(&exercise);
+                outln!(out, "{:<20} {:<28} {}", exercise.name, exercise.lesson, status);
+            }
+        }
+        Command::Export { format, dir } => {
+            let dir = Path::new(&dir);
+            fs::create_dir_all(dir)
+                .map_err(|err| Error::Io(format!("could not create {}: {}", dir.display(), err)))?;
+            for module in registry.modules() {
+                let sections = export::sections(&registry, module);
+                let path = dir.join(format!("{}.{}", module, format.extension()));
+                fs::write(&path, export::render(format, module, &sections))
+                    .map_err(|err| Error::Io(format!("could not write {}: {}", path.display(), err)))?;
+                outln!(out, "wrote {}", path.display());
+            }
+        }
+        Command::Snapshot { update } => {
+            let mode = if update { Mode::Update } else { Mode::Check };
+            let reports = snapshot::check_all(Path::new(SNAPSHOT_DIR), &registry, mode)
+                .map_err(|err| Error::Io(format!("could not check snapshots: {}", err)))?;
+            for report in &reports {
+                outln!(out, "{}", report);
+            }
+            let failed = reports.iter().filter(|report| report.failed()).count();
+            if failed > 0 {
+                return Err(Error::SnapshotsChanged(failed));
+            }
+        }
+        Command::Help => outln!(out, "{}", USAGE),
+    }
+    Ok(())
+}
+
+/// Look every name up first, so a typo in the last name does not leave us
+/// having run only half of what was asked for.
+fn find_all(registry: &Registry, names: Vec<String>) -> Result<Vec<&dyn Lesson>, Error> {
+    let mut lessons = Vec::new();
+    for name in names {
+        match registry.find(&name) {
+            Some(lesson) => lessons.push(lesson),
+            None => {
+                let suggestions = registry.suggestions(&name);
+                return Err(Error::UnknownLesson { name, suggestions });
+            }
+        }
+    }
+    Ok(lessons)
+}
+
+/// Run lessons under panic isolation and finish with a summary table. In
+/// annotated mode each lesson's output is held back and shown next to its
+/// code once the lesson finishes.
+fn run_lessons<'a, I>(lessons: I, out: &Output, annotated: bool) -> Result<(), Error>
+where
+    I: IntoIterator<Item = &'a dyn Lesson>,
+{
+    let reports = if annotated {
+        let mut reports = Vec::new();
+        for lesson in lessons {
+            let mut buffer = Vec::new();
+            let recording = Output::recording(&mut buffer);
+            reports.push(runner::run_lesson(lesson, &recording));
+            outln!(out, "{}", annotate::render(lesson, &recording.take_printed()));
+        }
+        reports
+    } else {
+        runner::run_all(lessons, out)
+    };
+
+    let now = progress::now();
+    update_progress(|progress| {
+        for report in reports.iter().filter(|report| report.passed()) {
+            progress.record_run(&report.lesson, now);
+        }
+    });
+
+    let summary = Summary(&reports);
+    outln!(out);
+    outln!(out, "{}", summary);
+    match summary.failed() {
+        0 => Ok(()),
+        failed => Err(Error::LessonsFailed(failed)),
+    }
+}
+
+/// Load the progress file, change it and save it again. Progress is a nice
+/// extra, so problems with the file are only warned about.
+fn update_progress<F: FnOnce(&mut Progress)>(change: F) {
+    let path = match progress::default_path() {
+        Some(path) => path,
+        None => return,
+    };
+    let result = Progress::load(&path).and_then(|mut progress| {
+        change(&mut progress);
+        progress.save(&path)
+    });
+    if let Err(err) = result {
+        eprintln!("warning: could not save progress to {}: {}", path.display(), err);
+    }
+}
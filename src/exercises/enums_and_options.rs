@@ -0,0 +1,73 @@
+// Exercise for intro_1::enums_and_options. Replace each todo!() so the check
+// at the bottom passes, then run `cargo run -- exercise next`.
+
+#![allow(unused_imports, unused_variables)]
+
+use std::f64::consts::PI;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// The key that moves in this direction: w, s, a or d.
+    pub fn key(&self) -> char {
+        todo!()
+    }
+
+    /// The direction a key moves in, None for any other key. This is the
+    /// opposite of key().
+    pub fn from_key(key: char) -> Option<Direction> {
+        todo!()
+    }
+}
+
+pub enum Shape {
+    Rectangle { width: u32, height: u32 },
+    Square(u32),
+    Circle(f64),
+}
+
+impl Shape {
+    /// Use PI for circles.
+    pub fn area(&self) -> f64 {
+        todo!()
+    }
+}
+
+/// x divided by y, or None when y is 0.
+pub fn division(x: f64, y: f64) -> Option<f64> {
+    todo!()
+}
+
+/// The result of a division with 7 places after the decimal point, or
+/// "cannot divide by 0" when there is no result.
+pub fn describe(result: Option<f64>) -> String {
+    todo!()
+}
+
+// The check below tests your code; there should be no need to change it.
+pub fn check() {
+    let directions = [Direction::Up, Direction::Down, Direction::Left, Direction::Right];
+    let keys: String = directions.iter().map(|direction| direction.key()).collect();
+    assert_eq!(keys, "wsad", "keys for up, down, left and right");
+    for &direction in &directions {
+        assert_eq!(Direction::from_key(direction.key()), Some(direction), "from_key(key())");
+    }
+    assert_eq!(Direction::from_key('q'), None, "from_key('q')");
+
+    assert_eq!(Shape::Rectangle { width: 10, height: 70 }.area(), 700.0, "rectangle area");
+    assert_eq!(Shape::Square(10).area(), 100.0, "square area");
+    let circle = Shape::Circle(4.5).area();
+    assert!((circle - 63.617_251_235).abs() < 1e-6, "circle area: {}", circle);
+
+    assert_eq!(division(5.0, 2.0), Some(2.5), "division(5.0, 2.0)");
+    assert_eq!(division(5.0, 0.0), None, "division(5.0, 0.0)");
+
+    assert_eq!(describe(division(5.0, 7.0)), "0.7142857", "describe(division(5.0, 7.0))");
+    assert_eq!(describe(None), "cannot divide by 0", "describe(None)");
+}
@@ -0,0 +1,157 @@
+// Practice versions of the lessons, in the style of rustlings. Each file in
+// this directory has the important parts replaced with todo!() and ends with
+// a check that passes once they are filled in correctly. Edit the file, then
+// `cargo run -- exercise next` rebuilds, runs the checks and points at the
+// first exercise that still needs work.
+
+use std::cell::Cell;
+use std::fmt;
+use std::panic;
+use std::sync::Once;
+
+use runner;
+
+pub mod enums_and_options;
+pub mod ownership;
+pub mod structures;
+
+pub struct Exercise {
+    pub name: &'static str,
+    /// Full name of the lesson this exercise practices.
+    pub lesson: &'static str,
+    /// The file to edit, relative to the crate root.
+    pub file: &'static str,
+    pub hint: &'static str,
+    /// Panics, like a test, when the exercise is not solved.
+    pub check: fn(),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Solved,
+    /// A todo!() is still there, at this line of the exercise if known.
+    Todo(Option<u32>),
+    /// The check failed with this message.
+    Failed(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Status::Solved => write!(f, "solved"),
+            Status::Todo(Some(line)) => write!(f, "todo!() left on line {}", line),
+            Status::Todo(None) => write!(f, "todo!() left"),
+            Status::Failed(ref message) => write!(f, "failed: {}", message),
+        }
+    }
+}
+
+/// Every exercise, in the same order as the lessons.
+pub fn builtin() -> Vec<Exercise> {
+    vec![
+        Exercise {
+            name: "ownership",
+            lesson: "intro_1::ownership",
+            file: "src/exercises/ownership.rs",
+            hint: "Iterators work on borrowed slices too: v.iter().filter(..).count(). \
+                   A function that owns a Vec may change it if the binding is `mut v`.",
+            check: ownership::check,
+        },
+        Exercise {
+            name: "structures",
+            lesson: "intro_1::structures",
+            file: "src/exercises/structures.rs",
+            hint: "Field init shorthand lets Rectangle::new return Rectangle { width, height }. \
+                   Display implementations use write!(f, ...).",
+            check: structures::check,
+        },
+        Exercise {
+            name: "enums_and_options",
+            lesson: "intro_1::enums_and_options",
+            file: "src/exercises/enums_and_options.rs",
+            hint: "match *self { Direction::Up => 'w', ... } and match on the Option in describe; \
+                   {:.7} formats with 7 places after the decimal point.",
+            check: enums_and_options::check,
+        },
+    ]
+}
+
+thread_local! {
+    /// Whether this thread is running a check, and so whether the panic hook
+    /// below should keep quiet about its panics.
+    static CHECKING: Cell<bool> = const { Cell::new(false) };
+    /// The line the last panic during a check on this thread came from.
+    static PANIC_LINE: Cell<Option<u32>> = const { Cell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Wrap the panic hook, once, in one that remembers where panics during a
+/// check came from instead of printing them, so an unfinished exercise is
+/// reported once, by us, instead of as a "thread panicked" message on
+/// stderr. Panics on every other thread, or outside a check, still go to
+/// the hook that was there before. The hook is never swapped back and
+/// forth, so checks on different threads cannot race over it.
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CHECKING.try_with(Cell::get).unwrap_or(false) {
+                let _ = PANIC_LINE.try_with(|line| line.set(info.location().map(|location| location.line())));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Marks this thread as running a check until dropped, even if something
+/// unwinds past it.
+struct Checking;
+
+impl Checking {
+    fn start() -> Checking {
+        PANIC_LINE.with(|line| line.set(None));
+        CHECKING.with(|checking| checking.set(true));
+        Checking
+    }
+}
+
+impl Drop for Checking {
+    fn drop(&mut self) {
+        let _ = CHECKING.try_with(|checking| checking.set(false));
+    }
+}
+
+/// Run an exercise's check. A panic from todo!() means it is not done yet,
+/// any other panic means the answer is not right yet.
+pub fn check(exercise: &Exercise) -> Status {
+    install_hook();
+    let result = {
+        let _checking = Checking::start();
+        panic::catch_unwind(exercise.check)
+    };
+
+    let payload = match result {
+        Ok(()) => return Status::Solved,
+        Err(payload) => payload,
+    };
+    let line = PANIC_LINE.with(Cell::get);
+    let message = runner::panic_message(payload.as_ref());
+    if message.starts_with("not yet implemented") {
+        Status::Todo(line)
+    } else {
+        match line {
+            Some(line) => Status::Failed(format!("{} (line {})", message, line)),
+            None => Status::Failed(message),
+        }
+    }
+}
+
+/// The first exercise that is not solved yet, with what is wrong with it.
+pub fn next(exercises: &[Exercise]) -> Option<(&Exercise, Status)> {
+    exercises
+        .iter()
+        .map(|exercise| (exercise, check(exercise)))
+        .find(|(_, status)| *status != Status::Solved)
+}
@@ -0,0 +1,55 @@
+// Exercise for intro_1::ownership. Replace each todo!() so the check at the
+// bottom passes, then run `cargo run -- exercise next`.
+//
+// Pay attention to the parameter types: a & means the function only borrows
+// the value, while a plain Vec or String means it takes ownership and has to
+// hand the value back if the caller wants to keep using it.
+
+#![allow(unused_variables)]
+#![allow(clippy::ptr_arg)]
+
+/// How many times `value` appears in `v`. `v` is only borrowed, so the
+/// caller still owns it afterwards.
+pub fn count(v: &[i32], value: i32) -> usize {
+    todo!()
+}
+
+/// Takes ownership of `v`, adds `value` to the end and gives the vector
+/// back.
+pub fn push_and_return(v: Vec<i32>, value: i32) -> Vec<i32> {
+    todo!()
+}
+
+/// Adds " world" to the end of `s` through a mutable borrow.
+pub fn append_world(s: &mut String) {
+    todo!()
+}
+
+/// The longest of `words` (the first one if several are as long), borrowed
+/// from the slice rather than cloned. None when there are no words.
+pub fn longest(words: &[String]) -> Option<&str> {
+    todo!()
+}
+
+// The check below tests your code; there should be no need to change it.
+pub fn check() {
+    let v = vec![4, 5, 3, 6, 7, 4, 8, 6, 4];
+    assert_eq!(count(&v, 4), 3, "count(&v, 4)");
+    assert_eq!(count(&v, 1), 0, "count(&v, 1)");
+
+    // v was only borrowed, so we can still give it away now.
+    let v = push_and_return(v, 2);
+    assert_eq!(v, [4, 5, 3, 6, 7, 4, 8, 6, 4, 2], "push_and_return(v, 2)");
+
+    let mut s = String::from("hello");
+    append_world(&mut s);
+    assert_eq!(s, "hello world", "append_world");
+
+    let words: Vec<String> = vec!["moving", "copying", "borrowing", "dropping"]
+        .into_iter()
+        .map(String::from)
+        .collect();
+    assert_eq!(longest(&words), Some("borrowing"), "longest");
+    assert_eq!(longest(&[]), None, "longest of no words");
+    assert_eq!(words.len(), 4, "words is still ours");
+}
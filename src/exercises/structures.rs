@@ -0,0 +1,60 @@
+// Exercise for intro_1::structures. Replace each todo!() so the check at the
+// bottom passes, then run `cargo run -- exercise next`.
+
+#![allow(unused_variables)]
+
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub struct Rectangle {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rectangle {
+    /// A related function (there is no self) that builds a Rectangle.
+    pub fn new(width: u32, height: u32) -> Rectangle {
+        todo!()
+    }
+
+    /// A rectangle whose sides are all `size` long.
+    pub fn square(size: u32) -> Rectangle {
+        todo!()
+    }
+
+    pub fn area(&self) -> u32 {
+        todo!()
+    }
+
+    /// Whether `other` fits inside this rectangle without turning it.
+    pub fn can_hold(&self, other: &Rectangle) -> bool {
+        todo!()
+    }
+}
+
+impl fmt::Display for Rectangle {
+    /// Prints like the lesson does: "(57, 83) and area: 4731".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        todo!()
+    }
+}
+
+// The check below tests your code; there should be no need to change it.
+pub fn check() {
+    let rect = Rectangle::new(35, 55);
+    assert_eq!(rect, Rectangle { width: 35, height: 55 }, "Rectangle::new(35, 55)");
+    assert_eq!(rect.area(), 1925, "area of 35x55");
+
+    let square = Rectangle::square(30);
+    assert_eq!(square, Rectangle { width: 30, height: 30 }, "Rectangle::square(30)");
+    assert_eq!(square.area(), 900, "area of a 30 square");
+
+    assert!(rect.can_hold(&square), "35x55 can hold a 30 square");
+    assert!(!square.can_hold(&rect), "a 30 square cannot hold 35x55");
+    assert!(
+        !rect.can_hold(&Rectangle::new(55, 35)),
+        "35x55 cannot hold 55x35 without turning it"
+    );
+
+    assert_eq!(Rectangle::new(57, 83).to_string(), "(57, 83) and area: 4731", "Display");
+}
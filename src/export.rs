@@ -0,0 +1,271 @@
+// Turns the notes into documents we can publish: one Markdown (or
+// self-contained HTML) file per module, with the comments as text, the code
+// in code blocks and what the code printed underneath.
+
+use lesson::{Lesson, Registry};
+use output::{Output, Printed};
+use runner::{self, Outcome};
+use source::Function;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Markdown,
+    Html,
+}
+
+impl Format {
+    pub fn extension(&self) -> &'static str {
+        match *self {
+            Format::Markdown => "md",
+            Format::Html => "html",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Block {
+    /// Explanation taken from the comments.
+    Prose(String),
+    Code(String),
+    /// What the code block before it printed.
+    Output(String),
+}
+
+/// One lesson, ready to be written out.
+#[derive(Debug)]
+pub struct Section {
+    pub full_name: String,
+    pub title: String,
+    pub blocks: Vec<Block>,
+}
+
+/// Run every lesson in `module` and build its sections.
+pub fn sections(registry: &Registry, module: &str) -> Vec<Section> {
+    registry.in_module(module).map(section).collect()
+}
+
+/// Run a lesson and split its source into prose, code and output.
+pub fn section(lesson: &dyn Lesson) -> Section {
+    let mut buffer = Vec::new();
+    let out = Output::recording(&mut buffer);
+    let report = runner::run_lesson(lesson, &out);
+    let printed = out.take_printed();
+
+    let mut blocks = match (lesson.source(), lesson.function()) {
+        (Some(source), Some(function)) => {
+            let printed: Vec<&Printed> = printed.iter().filter(|p| p.file == source.file).collect();
+            blocks(&function, &printed)
+        }
+        _ => vec![Block::Output(join(printed.iter().map(|p| p.text.as_str())))],
+    };
+    if let Outcome::Panicked(message) = report.outcome {
+        blocks.push(Block::Prose(format!("This lesson panicked: {}", message)));
+    }
+
+    Section {
+        full_name: lesson.full_name(),
+        title: lesson.title().to_string(),
+        blocks,
+    }
+}
+
+/// Where a line of the function body ends up.
+enum Line<'a> {
+    Prose(&'a str),
+    Code(&'a str),
+    Blank,
+}
+
+fn blocks(function: &Function, printed: &[&Printed]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut code: Vec<(u32, &str)> = Vec::new();
+    let mut in_block_comment = false;
+    let mut commented_code_depth = 0i32;
+
+    let signature = signature_line(function);
+    let last = function.first_line + function.lines.len() as u32 - 1;
+    for (number, text) in function.numbered() {
+        let trimmed = text.trim();
+        let line = if number == signature || number == last {
+            // the signature and closing brace are not interesting.
+            continue;
+        } else if in_block_comment || trimmed.starts_with("/*") {
+            in_block_comment = !trimmed.ends_with("*/");
+            Line::Prose(trimmed.trim_start_matches("/*").trim_end_matches("*/").trim())
+        } else if trimmed.starts_with("//") {
+            let comment = trimmed.trim_start_matches('/').trim();
+            // commented out code stays with the code.
+            if commented_code_depth > 0 || looks_like_code(comment) {
+                commented_code_depth += brace_balance(comment);
+                Line::Code(text)
+            } else {
+                Line::Prose(comment)
+            }
+        } else if trimmed.is_empty() {
+            Line::Blank
+        } else {
+            Line::Code(text)
+        };
+
+        match line {
+            Line::Prose(text) => {
+                flush_code(&mut code, printed, &mut blocks);
+                if !text.is_empty() {
+                    paragraph.push(text);
+                }
+            }
+            Line::Code(text) => {
+                flush_paragraph(&mut paragraph, &mut blocks);
+                code.push((number, text));
+            }
+            Line::Blank => {
+                flush_paragraph(&mut paragraph, &mut blocks);
+                if !code.is_empty() {
+                    code.push((number, ""));
+                }
+            }
+        }
+    }
+    flush_paragraph(&mut paragraph, &mut blocks);
+    flush_code(&mut code, printed, &mut blocks);
+    blocks
+}
+
+/// Line number of the `fn` line, which comes after any doc comments.
+fn signature_line(function: &Function) -> u32 {
+    function
+        .numbered()
+        .find(|&(_, text)| !text.trim_start().starts_with("///"))
+        .map_or(function.first_line, |(number, _)| number)
+}
+
+fn looks_like_code(comment: &str) -> bool {
+    comment.ends_with(';') || comment.ends_with('{') || comment.ends_with('}') || comment.starts_with('#')
+}
+
+fn brace_balance(text: &str) -> i32 {
+    text.chars()
+        .map(|c| match c {
+            '{' => 1,
+            '}' => -1,
+            _ => 0,
+        })
+        .sum()
+}
+
+fn flush_paragraph(paragraph: &mut Vec<&str>, blocks: &mut Vec<Block>) {
+    if !paragraph.is_empty() {
+        blocks.push(Block::Prose(paragraph.join(" ")));
+        paragraph.clear();
+    }
+}
+
+fn flush_code(code: &mut Vec<(u32, &str)>, printed: &[&Printed], blocks: &mut Vec<Block>) {
+    while code.last().is_some_and(|&(_, text)| text.trim().is_empty()) {
+        code.pop();
+    }
+    if code.is_empty() {
+        return;
+    }
+
+    // the notes are indented one level inside their function.
+    blocks.push(Block::Code(join(code.iter().map(|&(_, text)| dedent(text)))));
+    let output: Vec<&str> = printed
+        .iter()
+        .filter(|p| code.iter().any(|&(number, _)| number == p.line))
+        .map(|p| p.text.as_str())
+        .collect();
+    if !output.is_empty() {
+        blocks.push(Block::Output(join(output.into_iter())));
+    }
+    code.clear();
+}
+
+fn dedent(line: &str) -> &str {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    &line[indent.min(4)..]
+}
+
+fn join<'a, I: Iterator<Item = &'a str>>(lines: I) -> String {
+    lines.collect::<Vec<_>>().join("\n")
+}
+
+/// A Markdown document for one module.
+pub fn markdown(module: &str, sections: &[Section]) -> String {
+    let mut text = format!("# {}\n", module);
+    for section in sections {
+        text.push_str(&format!("\n## {} (`{}`)\n", section.title, section.full_name));
+        for block in &section.blocks {
+            match *block {
+                // a bare <J> would be read as an html tag.
+                Block::Prose(ref prose) => {
+                    text.push_str(&format!("\n{}\n", prose.replace('<', "\\<")))
+                }
+                Block::Code(ref code) => text.push_str(&format!("\n```rust\n{}\n```\n", code)),
+                Block::Output(ref output) => {
+                    text.push_str(&format!("\nOutput:\n\n```text\n{}\n```\n", output))
+                }
+            }
+        }
+    }
+    text
+}
+
+const STYLE: &str = "body { font-family: sans-serif; max-width: 50em; margin: 2em auto; line-height: 1.5; }
+pre { padding: 0.5em 1em; overflow-x: auto; }
+pre.code { background: #f4f4f4; }
+pre.output { background: #1e1e1e; color: #e0e0e0; }";
+
+/// A self-contained HTML page (no external styles or scripts) for one
+/// module.
+pub fn html(module: &str, sections: &[Section]) -> String {
+    let mut text = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{}\n</style>\n</head>\n<body>\n<h1>{}</h1>\n",
+        escape(module),
+        STYLE,
+        escape(module)
+    );
+    for section in sections {
+        text.push_str(&format!(
+            "<h2>{} (<code>{}</code>)</h2>\n",
+            escape(&section.title),
+            escape(&section.full_name)
+        ));
+        for block in &section.blocks {
+            match *block {
+                Block::Prose(ref prose) => text.push_str(&format!("<p>{}</p>\n", escape(prose))),
+                Block::Code(ref code) => {
+                    text.push_str(&format!("<pre class=\"code\"><code>{}</code></pre>\n", escape(code)))
+                }
+                Block::Output(ref output) => text.push_str(&format!(
+                    "<pre class=\"output\"><samp>{}</samp></pre>\n",
+                    escape(output)
+                )),
+            }
+        }
+    }
+    text.push_str("</body>\n</html>\n");
+    text
+}
+
+pub fn render(format: Format, module: &str, sections: &[Section]) -> String {
+    match format {
+        Format::Markdown => markdown(module, sections),
+        Format::Html => html(module, sections),
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,54 @@
+// Loose name matching for picking lessons by typing part of their name,
+// e.g. "ctrl flw" or "ownr" instead of "intro_1::control_flow".
+
+use std::cmp::Reverse;
+
+/// How well `query` matches `candidate`, or None if it does not match at
+/// all. Every character of the query has to appear in the candidate in
+/// order (spaces are ignored); matches that are together or at the start of
+/// a word score higher. Case does not matter.
+pub fn score(query: &str, candidate: &str) -> Option<i32> {
+    let query: Vec<char> = query
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    if query.is_empty() {
+        return None;
+    }
+
+    let mut score = 0;
+    let mut previous: Option<usize> = None;
+    let mut from = 0;
+    for &wanted in &query {
+        let found = (from..candidate.len()).find(|&i| candidate[i] == wanted)?;
+        score += 1;
+        if previous.map(|p| p + 1) == Some(found) {
+            score += 5;
+        }
+        if found == 0 || !candidate[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        if let Some(previous) = previous {
+            score -= (found - previous - 1).min(5) as i32;
+        }
+        previous = Some(found);
+        from = found + 1;
+    }
+    Some(score)
+}
+
+/// The candidates that match `query`, best first. Candidates with the same
+/// score keep their original order.
+pub fn rank<'a, I>(query: &str, candidates: I) -> Vec<(i32, &'a str)>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut ranked: Vec<(i32, &str)> = candidates
+        .into_iter()
+        .filter_map(|candidate| score(query, candidate).map(|s| (s, candidate)))
+        .collect();
+    ranked.sort_by_key(|&(score, _)| Reverse(score));
+    ranked
+}
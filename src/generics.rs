@@ -0,0 +1,146 @@
+// Some of these generic types are only declared to show the syntax.
+#![allow(dead_code)]
+
+use std::ops::Mul;
+
+use lesson::{NotesLesson, Registry};
+use numeric::{Fixed, Numeric};
+use output::Output;
+use quiz::{Answer, Question};
+use source::Source;
+
+/// This file, so lessons can show their code next to what it prints.
+pub const SOURCE: Source = Source {
+    file: file!(),
+    text: include_str!("generics.rs"),
+};
+
+pub fn register(registry: &mut Registry) {
+    registry.add(
+        NotesLesson::new(
+            "generics",
+            "generic_types",
+            "Generic structs, impls and trait bounds",
+            generic_types,
+        )
+        .tags(&["generics", "traits", "polymorphism"])
+        .source(SOURCE)
+        .questions(&[Question {
+            prompt: "What does a.print_me() return?",
+            answer: Answer::After("A<T> print_me example: "),
+        }]),
+    );
+}
+
+pub fn generic_types(out: &Output) {
+    // Generics look similar to Java generics.
+    #[derive(Debug)]
+    struct Square<T> {
+        _x: T,
+    }
+
+    // Except that you don't have to declare what
+    // type is used when you declare a variable.
+    let _s = Square { _x: 10 };
+    let _s = Square { _x: 1.0 };
+    let _s = Square { _x: "Hello" };
+    let _s = Square { _x: 'c' };
+
+    // define what a parameter in a method must implement in order to be a valid parameter:
+    use std::fmt;
+    fn print_debug<T: fmt::Debug>(out: &Output, x: T) {
+        outln!(out, "{:?}", x);
+    }
+
+    // Since Square derives Debug, it can be accepted as a parameter for print_debug().
+    print_debug(out, _s);
+
+    struct _A<T> {
+        x: T,
+    }
+
+    /* 
+    the first <J> here is declaring that we need <J> to be in scope for this
+    impl block. The A<J> is referencing the struct above, and denoting that
+    the J used in this impl block is the same J as in the A struct.
+
+    Remember T and J are just denoting that there is a generic involved,
+    so they don't have to match between the struct and the impl block.
+    */
+    impl<J> _A<J> {
+        fn print_me(&self) -> &J {
+            &self.x
+        }
+    }
+
+    let a = _A { x: "I am a string" };
+    outln!(out, "A<T> print_me example: {}", a.print_me());
+
+    // Multiple generics
+    struct _B<U, V> {
+        x: U,
+        y: V,
+    }
+
+    // 1 generic, multiple members of that same type (cannot create this
+    // struct with members of different types).
+    struct _C<Z> {
+        x: Z,
+        y: Z,
+    }
+
+    // Shape<T>, Rectangle<T> and Circle<T> are defined after this function
+    // instead of inside it, so other code can use them too.
+    let rectangle = Rectangle { x: 3, y: 4 };
+    outln!(out, "Rectangle<i32> area: {}", rectangle.area());
+
+    // The area of a circle needs pi, and Numeric gives every number type
+    // its own PI, so the same generic code works for each of them.
+    let radius = 100.1;
+    outln!(out, "Circle<f32> area: {}", Circle { radius: radius as f32 }.area());
+    outln!(out, "Circle<f64> area: {}", Circle { radius }.area());
+    outln!(out, "Circle<Fixed> area: {}", Circle { radius: Fixed::from_f64(radius) }.area());
+}
+
+/// A generic version of the Shape trait from traits.rs.
+pub trait Shape<T> {
+    fn area(&self) -> T;
+}
+
+// The types stored in x and y must be the same type and implement
+// the Mul trait (they must be able to be multiplied).
+pub struct Rectangle<T: Mul> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Shape<T> for Rectangle<T>
+// where clause lets you move the trait bounds (the list of
+// traits that T must implement) so that the code looks cleaner.
+// note how the trait bounds are delimited by plus (+) signs.
+where
+    T: Mul<Output = T> + Copy,
+{
+    // Since T has to implement Mul such that the output is T,
+    // and T implements Copy, we know we can multiple x and y
+    // and will get a value of type T we can return (will be copied).
+    // Unlike the areas elsewhere (see area.rs), this one is not checked:
+    // Mul is all T promises, and it has no checked or saturating version.
+    // So Rectangle<i32> overflows like any i32 multiplication (a panic in
+    // debug builds), and Rectangle<f64> gives inf.
+    fn area(&self) -> T {
+        self.x * self.y
+    }
+}
+
+pub struct Circle<T: Mul> {
+    pub radius: T,
+}
+
+impl<T: Numeric> Shape<T> for Circle<T> {
+    fn area(&self) -> T {
+        // 3.141 * (self.radius * self.radius) does not compile, because we
+        // don't know if T will be the same type as 3.141. Numeric::PI is a T.
+        T::PI * self.radius * self.radius
+    }
+}
@@ -1,83 +1,241 @@
-// call all intro 1 functions
-pub fn _intro_1_notes() {
-    _mutability();
-    _tuples();
-    _arrays();
-    _strings();
-    _ownership();
-    _structures();
-    _control_flow();
-    _enums_and_options();
-    _vectors_and_hashmaps();
-    _casting_and_lets_and_result();
+// These notes keep the code the way the tutorial wrote it, including older
+// syntax (`...` range patterns) and examples that only exist to be printed,
+// so the compiler and clippy are told not to complain about them here.
+#![allow(dead_code, unused_labels, ellipsis_inclusive_range_patterns)]
+#![allow(
+    clippy::approx_constant,
+    clippy::assign_op_pattern,
+    clippy::enum_variant_names,
+    clippy::into_iter_on_ref,
+    clippy::never_loop,
+    clippy::ptr_arg,
+    clippy::toplevel_ref_arg,
+    clippy::vec_init_then_push
+)]
+
+use area;
+use lesson::{NotesLesson, Registry};
+use output::Output;
+use quiz::{Answer, Question};
+use source::Source;
+use tracked::{Log, Tracked};
+
+/// This file, so lessons can show their code next to what it prints.
+pub const SOURCE: Source = Source {
+    file: file!(),
+    text: include_str!("intro_1.rs"),
+};
+
+// register every intro 1 function, in the order they should be read.
+pub fn register(registry: &mut Registry) {
+    registry.add(
+        NotesLesson::new(
+            "intro_1",
+            "mutability",
+            "Variables and mutability",
+            _mutability,
+        )
+        .tags(&["variables"])
+        .source(SOURCE)
+        .questions(&[Question {
+            prompt: "x starts as 5 and is then set to 10. What is the second line printed?",
+            answer: Answer::Line(1),
+        }]),
+    );
+    registry.add(
+        NotesLesson::new("intro_1", "tuples", "Tuples and debug printing", _tuples)
+            .tags(&["types", "printing"])
+            .source(SOURCE)
+            .questions(&[Question {
+                prompt: "What does printing tupl.1 with {:?} show?",
+                answer: Answer::Line(1),
+            }]),
+    );
+    registry.add(
+        NotesLesson::new(
+            "intro_1",
+            "arrays",
+            "Arrays, slices and memory size",
+            _arrays,
+        )
+        .tags(&["types", "collections", "memory"])
+        .source(SOURCE)
+        .questions(&[
+            Question {
+                prompt: "How many bytes does mem::size_of_val say [i32; 5] takes?",
+                answer: Answer::Line(3),
+            },
+            Question {
+                prompt: "What does the slice &arra[1..4] print?",
+                answer: Answer::Line(4),
+            },
+        ]),
+    );
+    registry.add(
+        NotesLesson::new("intro_1", "strings", "String and &str", _strings)
+            .tags(&["strings"])
+            .source(SOURCE),
+    );
+    registry.add(NotesLesson::new("intro_1", "ownership", "Ownership, moving, copying and borrowing", _ownership)
+        .tags(&["ownership", "borrowing", "memory"])
+        .source(SOURCE)
+        .questions(&[
+            Question {
+                prompt: "What does move_v print for v[10] + v[15]?",
+                answer: Answer::After("_move() took v: "),
+            },
+            Question {
+                prompt: "When the Tracked example's scope ends, which value is dropped first? (type the log line)",
+                answer: Answer::LineAfter("-- leaving the scope"),
+            },
+        ]));
+    registry.add(
+        NotesLesson::new(
+            "intro_1",
+            "structures",
+            "Structs, methods and Display",
+            _structures,
+        )
+        .tags(&["structs", "methods", "traits"])
+        .source(SOURCE)
+        .questions(&[Question {
+            prompt: "What area does Rectangle::new(57, 83).show() print?",
+            answer: Answer::Word {
+                line: "Rectangle show:",
+                index: 5,
+            },
+        }]),
+    );
+    registry.add(
+        NotesLesson::new(
+            "intro_1",
+            "control_flow",
+            "if, loops and match",
+            _control_flow,
+        )
+        .tags(&["control-flow", "loops", "match"])
+        .source(SOURCE)
+        .questions(&[
+            Question {
+                prompt: "What does the match on mat2 = 19 print?",
+                answer: Answer::LineAfter("Extra matching example, mat2: 19"),
+            },
+            Question {
+                prompt: "What is n2 after matching p2 = 14?",
+                answer: Answer::After("n2 was assigned: "),
+            },
+        ]),
+    );
+    registry.add(
+        NotesLesson::new(
+            "intro_1",
+            "enums_and_options",
+            "Enums, ref and Option",
+            _enums_and_options,
+        )
+        .tags(&["enums", "option", "match", "polymorphism"])
+        .source(SOURCE)
+        .questions(&[Question {
+            prompt: "What string does up_key.destruct() return?",
+            answer: Answer::After("up_key_string from destruct(): "),
+        }]),
+    );
+    registry.add(
+        NotesLesson::new(
+            "intro_1",
+            "vectors_and_hashmaps",
+            "Vec and HashMap",
+            _vectors_and_hashmaps,
+        )
+        .tags(&["collections", "memory"])
+        .source(SOURCE)
+        .questions(&[Question {
+            prompt: "v has length 4 and capacity 4. What is its capacity after v.push(9)?",
+            answer: Answer::After("v: [5, 6, 7, 8, 9] length: 5 capacity: "),
+        }]),
+    );
+    registry.add(NotesLesson::new("intro_1", "casting_and_lets_and_result", "if let, while let, casting and Result", _casting_and_lets_and_result)
+        .tags(&["option", "result", "casting", "panic"])
+        .source(SOURCE)
+        .questions(&[
+            Question {
+                prompt: "What is the while-let loop's last line, printed once i is over 19?",
+                answer: Answer::LineAfter("18"),
+            },
+            Question {
+                prompt: "What is `f as u8 as char` for f = 24.4321? (type an escape like \\u{41} for characters you cannot type)",
+                answer: Answer::Word { line: "24.4321", index: 2 },
+            },
+        ]));
 }
 
 // underscore suppresses the "unused" warning.
-pub fn _mutability() {
+pub fn _mutability(out: &Output) {
     // variables are immutable (constant) by default. mut makes them mutatable.
     let mut x: u32 = 5;
-    println!("{}", x);
+    outln!(out, "{}", x);
     // this only works with mut variables
     x = 10;
-    println!("{}", x);
+    outln!(out, "{}", x);
 }
 
-pub fn _tuples() {
+pub fn _tuples(out: &Output) {
     // tuple example. Can intermix types.
     let tupl = (1, "fifty");
 
     // print value
-    println!("{}", tupl.1);
+    outln!(out, "{}", tupl.1);
     // print debug (strings are surrounded by quotes ("))
-    println!("{:?}", tupl.1);
+    outln!(out, "{:?}", tupl.1);
     // debug the entire tuple
-    println!("{:?}", tupl);
+    outln!(out, "{:?}", tupl);
     // debug with pretty for the entire tuple
-    println!("{:#?}", tupl);
+    outln!(out, "{:#?}", tupl);
 
     // tuples that are too long cannot be printed
     // let tooLong = (1,2,3,4,5,6,7,8,9,10,11,12,13);
     // println!("{:?}", tooLong);
 }
 
-pub fn _arrays() {
+pub fn _arrays(out: &Output) {
     // array example
     let arra: [i32; 5] = [1,2,3,4,5];
-    println!("{:?}", arra);
+    outln!(out, "{:?}", arra);
     // print array item
-    println!("{:?}", arra[0]);
+    outln!(out, "{:?}", arra[0]);
     // print array length
-    println!("{:?}", arra.len());
+    outln!(out, "{:?}", arra.len());
 
     // import a library/external dependency
     use std::mem;
 
-    // print array memory size
-    println!("{:?}", mem::size_of_val(&arra));
+    // print array memory size. layout::lesson_types goes further, with the
+    // size, alignment and field offsets of the types from these notes.
+    outln!(out, "{:?}", mem::size_of_val(&arra));
 
     // taking slices. takes items 2,3, and 4. The min index is inclusive, the max index is exclusive.
     let slic = &arra[1..4];
-    println!("{:?}", slic);
+    outln!(out, "{:?}", slic);
 }
 
-pub fn _strings() {
+pub fn _strings(out: &Output) {
     // string literal ("mystring") is a slice of a string (&str).
     // strings are compound types of slices put together into a string.
 
     // strings can be treated like slices because of this.
     // to create a String with the type String, use this.
     let the_string = String::from("Hello ");
-    println!("{:?}", the_string);
+    outln!(out, "{:?}", the_string);
 
     // string concatenation example. Note the reference (&another_string).
     let another_string = String::from("World!");
     let combined_string = the_string + &another_string;
-    println!("{}", combined_string);
+    outln!(out, "{}", combined_string);
 }
 
 /// This function covers notes about ownership and borrowing, with some
 /// introduction to memory references and dereferencing.
-pub fn _ownership() {
+pub fn _ownership(out: &Output) {
     // scope ends at the closing curly brace, so we cannot print _a after its scope ends.
     {
         let _a = 10;
@@ -90,102 +248,134 @@ pub fn _ownership() {
     // "borrowing" allows another variable to use its owned value.
     let strin = String::from("myString");
     let other_strin_var = &strin;
-    println!("{}", other_strin_var);
+    outln!(out, "{}", other_strin_var);
 
     // "moving" a value as a parameter to a function causes us to lose access
     // to it since we gave it to the function.
     // Vec is like an expandable array (think python list). It is stored in
     // the heap, so it is a referenced value, rather than primitives which are
     // stored in the stack.
-    println!("Moving example start.");
+    outln!(out, "Moving example start.");
     let mut v = Vec::new();
     for i in 1..100 {
         v.push(i);
     }
 
-    fn move_v(v: Vec<i32>) {
-        println!("_move() took v: {}", v[10] + v[15]);
+    fn move_v(out: &Output, v: Vec<i32>) {
+        outln!(out, "_move() took v: {}", v[10] + v[15]);
     }
-    move_v(v);
+    move_v(out, v);
     // If we do not get ownership of the referenced v back, then we can no
     // longer use it since we gave it to _move().
     // println!("{}", v[0]);
-    println!("Moving example end.");
+    outln!(out, "Moving example end.");
 
     // "copying" example. Similar to "move", but a parameter is passed by copy
     // of the value. This is what happens with primitives (this example uses i32)
     // with the same syntax as the moving example.
-    println!("Copying example start.");
+    outln!(out, "Copying example start.");
     let a = 20;
     let b = 30;
-    println!("before calling _copy() main has a: {} and b: {}", a, b);
+    outln!(out, "before calling _copy() main has a: {} and b: {}", a, b);
 
-    fn copy(a: i32, b: i32) {
-        println!("_copy() combined a and b to make: {}", a + b);
+    fn copy(out: &Output, a: i32, b: i32) {
+        outln!(out, "_copy() combined a and b to make: {}", a + b);
     }
-    copy(a, b);
-    println!("After calling _copy() main still has a: {} and b: {}", a, b);
-    println!("Copying example end.");
+    copy(out, a, b);
+    outln!(out, "After calling _copy() main still has a: {} and b: {}", a, b);
+    outln!(out, "Copying example end.");
 
     // Bigger "borrowing" example.
-    println!("Borrowing example start.");
+    outln!(out, "Borrowing example start.");
     let mut v2 = Vec::new();
     for i in 1..100 {
         v2.push(i);
     }
-    println!("created v2. for testing, the item with index 50 is: {}", v2[50]);
+    outln!(out, "created v2. for testing, the item with index 50 is: {}", v2[50]);
 
     // v2 gets returned after it is borrowed by the function,
     // so v2 has ownership of its value again.
-    fn return_after_borrowing(v: Vec<i32>) -> Vec<i32> {
-        println!("_return_after_borrowing() borrowed and will return v2: {}", v[50] + v[51]);
+    fn return_after_borrowing(out: &Output, v: Vec<i32>) -> Vec<i32> {
+        outln!(out, "_return_after_borrowing() borrowed and will return v2: {}", v[50] + v[51]);
         v
     }
-    v2 = return_after_borrowing(v2);
-    println!("after being returned from a function that took v2 as Vec as a param: {}", v2[50]);
+    v2 = return_after_borrowing(out, v2);
+    outln!(out, "after being returned from a function that took v2 as Vec as a param: {}", v2[50]);
 
     // pass a reference to the function. The function will dereference v2 to access it.
     // When the function is finished, then v2 will own the vector again.
-    fn borrow_dereference(v: &Vec<i32>) {
-        println!("_borrow_dereference borrowed and dereferenced v2: {}", (*v)[50] + (*v)[51]);
+    fn borrow_dereference(out: &Output, v: &Vec<i32>) {
+        outln!(out, "_borrow_dereference borrowed and dereferenced v2: {}", (*v)[50] + (*v)[51]);
     }
-    borrow_dereference(&v2);
-    println!("after being passed as a reference to a function that took v2 and dereferenced it, v2 can be accessed in main: {}", v2[50]);
+    borrow_dereference(out, &v2);
+    outln!(out, "after being passed as a reference to a function that took v2 and dereferenced it, v2 can be accessed in main: {}", v2[50]);
 
     // pass a reference to the function. It sounds like there is
     // some automatic dereferencing happening here. 
     // When the function is finished, then v2 will own the vector again.
-    fn borrow_without_dereference(v: &Vec<i32>) {
-        println!("borrow_without_dereference borrowed v2: {}", v[50] + v[51]);
+    fn borrow_without_dereference(out: &Output, v: &Vec<i32>) {
+        outln!(out, "borrow_without_dereference borrowed v2: {}", v[50] + v[51]);
     }
-    borrow_without_dereference(&v2);
-    println!("after being passed as a reference to a function, v2 can be accessed in main: {}", v2[50]);
-    println!("Borrowing example end.");
+    borrow_without_dereference(out, &v2);
+    outln!(out, "after being passed as a reference to a function, v2 can be accessed in main: {}", v2[50]);
+    outln!(out, "Borrowing example end.");
 
     // example of borrowing with loop and function.
     // loop borrows, then function borrows.
-    println!("Loop and function borrowing example start.");
+    outln!(out, "Loop and function borrowing example start.");
 
     // vec! is a macro for creating a vector.
     let v3 = vec![4, 5, 3, 6, 7, 4, 8, 6, 4, 2, 4, 2, 5, 3, 7, 7];
-    println!("created v3: {}", v3[0]);
+    outln!(out, "created v3: {}", v3[0]);
 
     fn count(v: &Vec<i32>, value: i32) -> usize {
         v.into_iter().filter(|&&x| x == value).count()
     }
     for &i in &v3 {
         let i_count = count(&v3, i);
-        println!("{} is repeated {} times", i, i_count);
+        outln!(out, "{} is repeated {} times", i, i_count);
     }
     // v3 is owned after the loop completes because it was only passed by
     // reference to the loop and function.
-    println!("v3 is owned after the loop: {}", v3[0]);
-    println!("Loop and function borrowing example end.");
+    outln!(out, "v3 is owned after the loop: {}", v3[0]);
+    outln!(out, "Loop and function borrowing example end.");
+
+    // Watching ownership happen. Tracked (see tracked.rs) wraps a value and
+    // writes down in a log when it is created, cloned and dropped, so we can
+    // see exactly where each value stops being owned.
+    outln!(out, "Tracked example start.");
+    let log = Log::new();
+    {
+        let v4 = log.track("v4", vec![1, 2, 3]);
+        // like move_v, this takes ownership, so v4 is dropped when it returns.
+        fn tracked_move(log: &Log, v: Tracked<Vec<i32>>) {
+            log.note(&format!("tracked_move() took {} with {} items", v.label(), v.len()));
+        }
+        tracked_move(&log, v4);
+        log.note("back from tracked_move()");
+
+        // like return_after_borrowing, ownership comes back with the return
+        // value, so nothing is dropped.
+        fn tracked_return(log: &Log, v: Tracked<Vec<i32>>) -> Tracked<Vec<i32>> {
+            log.note(&format!("tracked_return() took {} and gives it back", v.label()));
+            v
+        }
+        let v5 = tracked_return(&log, log.track("v5", vec![4, 5, 6]));
+
+        // clone makes a second, independent owner.
+        let _v5_copy = v5.clone();
+        log.note("leaving the scope");
+    }
+    // variables are dropped in the reverse order they were created.
+    for event in log.events() {
+        outln!(out, "{}", event);
+    }
+    outln!(out, "Tracked example end.");
 }
 
 /// This function covers notes about structures, methods, related functions
 /// (like Java static methods), and display/debug traits.
-pub fn _structures() {
+pub fn _structures(out: &Output) {
     // struct contains data (properties).
     // The derive annotation is for deriving the Debug trait, used later for
     // printing a Rectangle with debug info in println!.
@@ -196,8 +386,10 @@ pub fn _structures() {
     }
 
     // access properties with a period (.)
-    fn area(rect: &Rectangle) -> u32 {
-        rect.width * rect.height
+    // width * height could overflow a u32 (70000 * 70000 does), so the area
+    // is worked out as a u64, which always has room (see area.rs).
+    fn area(rect: &Rectangle) -> u64 {
+        area::widening(rect.width, rect.height)
     }
 
     // instantiate a Rectangle structure.
@@ -206,19 +398,19 @@ pub fn _structures() {
         height: 55,
     };
 
-    println!("Rectangle with {}x{} has area: {}", rect.width, rect.height, area(&rect));
+    outln!(out, "Rectangle with {}x{} has area: {}", rect.width, rect.height, area(&rect));
 
     // Create an implementation of Rectangle that has a method.
     impl Rectangle {
-        fn area(&self) -> u32 {
-            self.width * self.height
+        fn area(&self) -> u64 {
+            area::widening(self.width, self.height)
         }
 
-        fn show(&self) {
-            println!("Rectangle show: {}x{} has area: {}", self.width, self.height, self.area());
+        fn show(&self, out: &Output) {
+            outln!(out, "Rectangle show: {}x{} has area: {}", self.width, self.height, self.area());
         }
     }
-    println!("Rectangle area method: {}x{} has area: {}", rect.width, rect.height, rect.area());
+    outln!(out, "Rectangle area method: {}x{} has area: {}", rect.width, rect.height, rect.area());
 
     // Related functions are like static methods in Java. Related functions
     // can be defined in the same impl block, or in a separate one like this.
@@ -237,11 +429,11 @@ pub fn _structures() {
 
     // Use the related function to create a new rectangle.
     let new_rect = Rectangle::new(57, 83);
-    new_rect.show();
+    new_rect.show(out);
 
     // Because of the derived Debug trait on Rectangle, we can print it with
     // debug info.
-    println!("Debug new_rect: {:#?}", new_rect);
+    outln!(out, "Debug new_rect: {:#?}", new_rect);
 
     // import for the Display trait.
     use std::fmt;
@@ -257,10 +449,10 @@ pub fn _structures() {
     }
 
     // With the Display trait, we can print it without debug.
-    println!("Print (Display) new_rect: {}", new_rect);
+    outln!(out, "Print (Display) new_rect: {}", new_rect);
 }
 
-pub fn _control_flow() {
+pub fn _control_flow(out: &Output) {
     // logical operators: == != < > >= <=
     let num = 6;
 
@@ -269,16 +461,16 @@ pub fn _control_flow() {
 
     // if block
     if num < 10 {
-        println!("{} is less than 10", num);
+        outln!(out, "{} is less than 10", num);
     }
 
     // if with else if and else
     if num % 4 == 0 {
-        println!("{} is divisible by 4", num);
+        outln!(out, "{} is divisible by 4", num);
     } else if num % 3 == 0 {
-        println!("{} is divisible by 3", num);
+        outln!(out, "{} is divisible by 3", num);
     } else {
-        println!("example of an else block.");
+        outln!(out, "example of an else block.");
     }
 
     // binding (initialize/set a variable) with if and else.
@@ -289,7 +481,7 @@ pub fn _control_flow() {
     } else {
         76
     };
-    println!("myNum: {}", my_num);
+    outln!(out, "myNum: {}", my_num);
 
     // Infinite loop example
     // loop {
@@ -299,7 +491,7 @@ pub fn _control_flow() {
     // loop example
     let mut count = 0;
     loop {
-        println!("in loop, count: {}", count);
+        outln!(out, "in loop, count: {}", count);
         count += 1;
 
         if count >= 10 {
@@ -314,11 +506,11 @@ pub fn _control_flow() {
     // nested loop with labels example. Nested loops can break by label.
     let mut end_condition = false;
     'a: loop {
-        println!("loop a");
+        outln!(out, "loop a");
         'b: loop {
-            println!("loop b");
+            outln!(out, "loop b");
             'c: loop {
-                println!("loop c");
+                outln!(out, "loop c");
 
                 if end_condition {
                     // end our loops by breaking the outermost one.
@@ -343,101 +535,101 @@ pub fn _control_flow() {
         // break acts like "return" in this context.
         break 10;
     };
-    println!("x = {}", x);
+    outln!(out, "x = {}", x);
 
     // while loop example
     let mut get_to_zero = 10;
     while get_to_zero != 0 {
-        println!("while loop: {}!", get_to_zero);
+        outln!(out, "while loop: {}!", get_to_zero);
         get_to_zero = get_to_zero - 1;
     }
 
     // for loop example. for loops look similar to python.
     let my_vec = vec![1, 2, 3, 4, 5];
     for i in my_vec {
-        println!("for loop vec example i: {}", i);
+        outln!(out, "for loop vec example i: {}", i);
     }
 
     // for loop without a list/array/vector.
     // Note that the range does not include the endpoint
     // (prints to 9 rather than 10).
     for i in 1..10 {
-        println!("for loop with range example i: {}", i);
+        outln!(out, "for loop with range example i: {}", i);
     }
 
     // for loop with inclusive range.
     // Was experimental when the tutorial was published, but it seems to work now.
     for i in 1..=10 {
-        println!("for loop with range INCLUSIVE example i: {}", i);
+        outln!(out, "for loop with range INCLUSIVE example i: {}", i);
     }
 
     // match example. Similar to switch statements in other languages.
     let mat = 5;
     match mat {
-        1 => println!("one"),
-        2 => println!("two"),
-        3 => println!("three"),
-        4 => println!("four"),
-        5 => println!("five"),
-        _ => println!("something else. This is like a default."),
+        1 => outln!(out, "one"),
+        2 => outln!(out, "two"),
+        3 => outln!(out, "three"),
+        4 => outln!(out, "four"),
+        5 => outln!(out, "five"),
+        _ => outln!(out, "something else. This is like a default."),
     }
 
     // match example with multiple conditions leading to the same case.
     // includes single bar (|) or-like options and a range using ...
     let mat2 = 19;
-    println!("Extra matching example, mat2: {}", mat2);
+    outln!(out, "Extra matching example, mat2: {}", mat2);
     match mat2 {
-        1 => println!("one"),
-        2 | 3 | 5 | 7 | 11 => println!("This is a prime"),
+        1 => outln!(out, "one"),
+        2 | 3 | 5 | 7 | 11 => outln!(out, "This is a prime"),
         // note this range is inclusive, including 19
-        13 ... 19 => println!("This is a \"teen\"", ),
-        _ => println!("This is not special", ),
+        13 ... 19 => outln!(out, "This is a \"teen\"", ),
+        _ => outln!(out, "This is not special", ),
     }
 
     // match with conditions using tuples. We can match on one index,
     // then retrieve the other index for use in a case
     // (in this example, print it out).
     let my_tuple = (0, -2);
-    println!("matching example with tuples: {:?}", my_tuple);
+    outln!(out, "matching example with tuples: {:?}", my_tuple);
     match my_tuple {
-        (0, y) => println!("first index is 0, y: {}", y),
-        (x, 0) => println!("second index is 0, x: {}", x),
-        _ => println!("tuple has no match"),
+        (0, y) => outln!(out, "first index is 0, y: {}", y),
+        (x, 0) => outln!(out, "second index is 0, x: {}", x),
+        _ => outln!(out, "tuple has no match"),
     }
 
     // match with extra conditions.
     let pair = (5, -5);
-    println!("matching with extra conditions: {:?}", pair);
+    outln!(out, "matching with extra conditions: {:?}", pair);
     match pair {
-        (x, y) if x == y => println!("x equals y"),
-        (x, y) if x + y == 0 => println!("x plus y equals zero"),
-        (x, _) if x % 2 == 0 => println!("x is even"),
-        _ => println!("no match"),
+        (x, y) if x == y => outln!(out, "x equals y"),
+        (x, y) if x + y == 0 => outln!(out, "x plus y equals zero"),
+        (x, _) if x % 2 == 0 => outln!(out, "x is even"),
+        _ => outln!(out, "no match"),
     }
 
     // match with binding a variable to the matched value
     // good for accessing a value that we do not have ownership of,
     // since the bound variable (n) is basically a clone of the given value (p).
     let p = 5;
-    println!("matching with binding a variable to the match: {}", p);
+    outln!(out, "matching with binding a variable to the match: {}", p);
     match p {
-        n @ 1 ... 12 => println!("n is between 1-12: {}", n),
-        n @ 13 ... 19 => println!("n is between 13-19: {}", n),
-        _ => println!("no match"),
+        n @ 1 ... 12 => outln!(out, "n is between 1-12: {}", n),
+        n @ 13 ... 19 => outln!(out, "n is between 13-19: {}", n),
+        _ => outln!(out, "no match"),
     }
 
     // match can also be used to bind a variable.
     let p2 = 14;
-    println!("binding a variable with a match: {}", p2);
+    outln!(out, "binding a variable with a match: {}", p2);
     let n2 = match p2 {
         n @ 1 ... 12 => n + 1,
         n @ 13 ... 19 => n + 2,
         _ => 0,
     };
-    println!("n2 was assigned: {}", n2);
+    outln!(out, "n2 was assigned: {}", n2);
 }
 
-pub fn _enums_and_options() {
+pub fn _enums_and_options(out: &Output) {
     // allows dead, unused code. Another annotation will disallow dead code.
     //#![allow(dead_code)]
 
@@ -500,39 +692,44 @@ pub fn _enums_and_options() {
     // create an instance of the up direction
     let up_dir = Direction::_Up(Point {x: 0, y: 1});
     let up_key = up_dir.match_direction();
-    println!("up_key: {:?}", up_key);
+    outln!(out, "up_key: {:?}", up_key);
     let up_key_string = up_key.destruct();
-    println!("up_key_string from destruct(): {}", up_key_string);
+    outln!(out, "up_key_string from destruct(): {}", up_key_string);
 
     // intro to ref keyword. ref keyword creates a reference to a value.
     let u = 10; // u owns 10
     let v = &u; // v gets a reference to the 10 that u owns
     let ref z = u; // z gets a reference to the 10 that u owns
-    println!("ref keyword with debug values: u: {:?} v: {:?} z: {:?}", u, v, z);
+    outln!(out, "ref keyword with debug values: u: {:?} v: {:?} z: {:?}", u, v, z);
     // prove that z and v are the same. You cannot compare u == v because a
     // reference to an i32 is a different type from an i32.
     if z == v {
-        println!("z == v evaluated to true.");
+        outln!(out, "z == v evaluated to true.");
     } else {
-        println!("z == v evaluated to false.");
+        outln!(out, "z == v evaluated to false.");
     }
 
-    // Polymorphism example, implemented using match and enums.
+    // Polymorphism example, implemented using match and enums. AnyShape in
+    // shapes/mod.rs is the full version, next to a trait based one.
     enum Shape {
         Rectangle {width: u32, height: u32},
         Square(u32),
         Circle(f64),
     }
 
+    // the standard library has pi, more precisely than typing out 3.14.
+    use std::f64::consts::PI;
+
     // define method that any Shape can use.
     impl Shape {
         fn area(&self) -> f64 {
             match *self {
                 // note the type cast to f64. This is done to keep the
                 // returned type consistent since the circle will return an f64.
-                Shape::Rectangle {width, height} => (width * height) as f64,
-                Shape::Square(ref s) => (s * s) as f64,
-                Shape::Circle(ref r) => 3.14 * (r * r),
+                // area::widening multiplies without overflowing.
+                Shape::Rectangle {width, height} => area::widening(width, height) as f64,
+                Shape::Square(s) => area::widening(s, s) as f64,
+                Shape::Circle(ref r) => PI * (r * r),
             }
         }
     }
@@ -543,15 +740,15 @@ pub fn _enums_and_options() {
     let square = Shape::Square(10);
     let circle = Shape::Circle(4.5);
 
-    println!("polymorphism example:", );
+    outln!(out, "polymorphism example:", );
     let rect_area = rect.area();
-    println!("rect area: {}", rect_area);
+    outln!(out, "rect area: {}", rect_area);
 
     let square_area = square.area();
-    println!("rect area: {}", square_area);
+    outln!(out, "rect area: {}", square_area);
 
     let circle_area = circle.area();
-    println!("rect area: {}", circle_area);
+    outln!(out, "rect area: {}", circle_area);
 
     // options. Option enum from std library looks like:
     // enum Option<T> {
@@ -575,12 +772,12 @@ pub fn _enums_and_options() {
     // let res = division(5.0, 0.0);
     match res {
         // display 7 places past the decimal point.
-        Some(x) => println!("{:.7}", x),
-        None => println!("cannot divide by 0"),
+        Some(x) => outln!(out, "{:.7}", x),
+        None => outln!(out, "cannot divide by 0"),
     }
 }
 
-pub fn _vectors_and_hashmaps() {
+pub fn _vectors_and_hashmaps(out: &Output) {
     // vectors have variable length and can grow or shrink.
     // slices (think strings) are very similar.
     // they are like lists in python.
@@ -593,28 +790,29 @@ pub fn _vectors_and_hashmaps() {
 
     // iterating over elements in a vector
     for elem in &v {
-        println!("{}", elem);
+        outln!(out, "{}", elem);
     }
 
     // list everything in the vector with debug info.
     // The length and capacity can be accessed with these methods.
-    println!("v: {:?} length: {} capacity: {}", &v, v.len(), v.capacity());
+    outln!(out, "v: {:?} length: {} capacity: {}", &v, v.len(), v.capacity());
 
-    // see the vector grow
+    // see the vector grow. v is full, so this push has to reallocate it on
+    // the heap (build with --features count-allocations to see it counted).
     v.push(9);
-    println!("v: {:?} length: {} capacity: {}", &v, v.len(), v.capacity());
+    outln!(out, "v: {:?} length: {} capacity: {}", &v, v.len(), v.capacity());
 
     // vectors have the pop method (think stacks) to remove the last element
     // from the vector and returns an option containing the element.
     // Note that since it is an option, it may return None.
-    println!("{:?}", v.pop());
-    println!("v: {:?}", &v);
+    outln!(out, "{:?}", v.pop());
+    outln!(out, "v: {:?}", &v);
 
     // empty vector test
-    println!("empty vector:");
+    outln!(out, "empty vector:");
     let mut empty_vec: Vec<i32> = Vec::new();
-    println!("empty_vec: {:?} length: {} capacity: {}", &empty_vec, empty_vec.len(), empty_vec.capacity());
-    println!("empty_vec.pop(): {:?}", empty_vec.pop());
+    outln!(out, "empty_vec: {:?} length: {} capacity: {}", &empty_vec, empty_vec.len(), empty_vec.capacity());
+    outln!(out, "empty_vec.pop(): {:?}", empty_vec.pop());
 
     // example of using polymorphism through enums to get multiple types
     // into the same vector.
@@ -630,7 +828,7 @@ pub fn _vectors_and_hashmaps() {
         Example::Float(12.32),
         Example::Text(String::from("string")),
     ];
-    println!("multitype example: {:?}", &multitype_vec);
+    outln!(out, "multitype example: {:?}", &multitype_vec);
 
     // hashmaps
     use std::collections::HashMap;
@@ -643,22 +841,26 @@ pub fn _vectors_and_hashmaps() {
     hm.insert(String::from("strings"), 49);
 
     // iterate over the keys and values.
-    println!("Hashmap hm key: value list");
-    for (k, v) in &hm {
-        println!("{}: {}", k, v);
+    // A HashMap does not keep its items in any order, and the order can
+    // change every time the program runs, so sort them before printing.
+    outln!(out, "Hashmap hm key: value list");
+    let mut pairs: Vec<_> = hm.iter().collect();
+    pairs.sort();
+    for (k, v) in pairs {
+        outln!(out, "{}: {}", k, v);
     }
 
     // get a value (in an option) by giving a key.
     // use match so you can cover the case where a bad key is given,
     // which will cause get to return None.
-    println!("using hm.get(\"random\"):");
+    outln!(out, "using hm.get(\"random\"):");
     match hm.get(&String::from("random")) {
-        Some(&n) => println!("{}", n),
-        _ => println!("no match"),
+        Some(&n) => outln!(out, "{}", n),
+        _ => outln!(out, "no match"),
     }
 }
 
-pub fn _casting_and_lets_and_result() {
+pub fn _casting_and_lets_and_result(out: &Output) {
     // This is what we used so far to handle options:
     let s = Some('c');
     // match s {
@@ -669,14 +871,14 @@ pub fn _casting_and_lets_and_result() {
     // the if-let will give us some easier to read syntax when we are working
     // with options that will either be something or None.
     if let Some(i) = s {
-        println!("if-let example s: {}", i);
+        outln!(out, "if-let example s: {}", i);
     }// else {
     //     {}
     // }
 
     // example loop with a counter inside an option.
     // counts by 2 and then quits at 20.
-    println!("while-let example: ");
+    outln!(out, "while-let example: ");
     let mut s = Some(0);
     // loop {
     //     match s {
@@ -696,10 +898,10 @@ pub fn _casting_and_lets_and_result() {
     // example of a while-let loop, does the same thing as the above loop
     while let Some(i) = s {
         if i > 19 {
-            println!("Quit");
+            outln!(out, "Quit");
             s = None;
         } else {
-            println!("{}", i);
+            outln!(out, "{}", i);
             s = Some(i + 2);
         }
     }
@@ -710,8 +912,8 @@ pub fn _casting_and_lets_and_result() {
     let c = i as char;
 
     // c ends up as some wierd character here.
-    println!("casting example: f, i, c");
-    println!("{} {} {}", f, i, c);
+    outln!(out, "casting example: f, i, c");
+    outln!(out, "{} {} {}", f, i, c);
 
     // only u8 can be cast to char. 256 is out of range of char,
     // but 255 is in range.
@@ -725,7 +927,7 @@ pub fn _casting_and_lets_and_result() {
     // }
 
     // example with Result. Includes opening a File and panic!.
-    println!("Result example (hopefully we don't panic!)", );
+    outln!(out, "Result example (hopefully we don't panic!)", );
     use std::fs::File;
     // it looks like rust File can use slashes (unix style) or
     // backslashes (windows style) when referencing a path to a file.
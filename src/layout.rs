@@ -0,0 +1,218 @@
+// How the types from the lessons are laid out in memory: their size,
+// alignment, where each field sits, and whether Option can hide its None in
+// a value the type can never have (a "niche") instead of adding a tag.
+//
+// Rust is free to lay out types without #[repr(C)] however it likes, and
+// pointers are 4 bytes on 32-bit targets, so the numbers depend on the
+// compiler and the machine. That is why this lesson has no snapshot.
+//
+// The lessons define their types inside their functions, where nothing
+// outside can name them, so this module has copies with the same fields.
+#![allow(dead_code)]
+
+use std::fmt;
+use std::mem;
+
+use lesson::{NotesLesson, Registry};
+use output::Output;
+use snapshot;
+use source::Source;
+
+/// This file, so the lesson can show its code next to what it prints.
+pub const SOURCE: Source = Source {
+    file: file!(),
+    text: include_str!("layout.rs"),
+};
+
+pub fn register(registry: &mut Registry) {
+    registry.add(
+        NotesLesson::new(
+            "layout",
+            "lesson_types",
+            "Size, alignment and niches of the lesson types",
+            _lesson_types,
+        )
+        .tags(&[
+            "memory",
+            "types",
+            "enums",
+            "option",
+            snapshot::MACHINE_SPECIFIC,
+        ])
+        .source(SOURCE),
+    );
+}
+
+/// Same as in intro_1::structures.
+pub struct Rectangle {
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Same as in intro_1::enums_and_options.
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Same as in intro_1::enums_and_options.
+pub enum Direction {
+    Up(Point),
+    Down(Point),
+    Left(Point),
+    Right(Point),
+}
+
+/// Same as in intro_1::enums_and_options.
+pub enum Keys {
+    UpKey(String),
+    DownKey(String),
+    LeftKey(String),
+    RightKey(String),
+}
+
+/// Same as in intro_1::vectors_and_hashmaps.
+pub enum Example {
+    Int(i32),
+    Float(f64),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub name: &'static str,
+    pub offset: usize,
+    pub size: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layout {
+    pub name: &'static str,
+    pub size: usize,
+    pub align: usize,
+    /// Size of Option<T>, which is the same as `size` when T has a niche.
+    pub option_size: usize,
+    /// Fields in memory order, which is not always the order they are
+    /// declared in.
+    pub fields: Vec<Field>,
+    /// Why the layout looks the way it does.
+    pub note: &'static str,
+}
+
+impl Layout {
+    pub fn of<T>(name: &'static str) -> Layout {
+        Layout {
+            name,
+            size: mem::size_of::<T>(),
+            align: mem::align_of::<T>(),
+            option_size: mem::size_of::<Option<T>>(),
+            fields: Vec::new(),
+            note: "",
+        }
+    }
+
+    /// Add a field of type F found at `offset` (from mem::offset_of!).
+    pub fn field<F>(mut self, name: &'static str, offset: usize) -> Layout {
+        let field = Field {
+            name,
+            offset,
+            size: mem::size_of::<F>(),
+        };
+        let at = self.fields.iter().take_while(|other| other.offset <= offset).count();
+        self.fields.insert(at, field);
+        self
+    }
+
+    pub fn note(mut self, note: &'static str) -> Layout {
+        self.note = note;
+        self
+    }
+
+    /// Option<T> fits in the same space as T.
+    pub fn has_niche(&self) -> bool {
+        self.option_size == self.size
+    }
+
+    /// Bytes that belong to no field. Only known when the fields are.
+    pub fn padding(&self) -> Option<usize> {
+        if self.fields.is_empty() {
+            None
+        } else {
+            Some(self.size - self.fields.iter().map(|field| field.size).sum::<usize>())
+        }
+    }
+}
+
+/// Layouts of the types used in the lessons.
+pub fn layouts() -> Vec<Layout> {
+    vec![
+        Layout::of::<Rectangle>("Rectangle")
+            .field::<u32>("width", mem::offset_of!(Rectangle, width))
+            .field::<u32>("height", mem::offset_of!(Rectangle, height))
+            .note("Two u32s side by side. Any bit pattern is a valid u32, so Option needs a tag of its own."),
+        Layout::of::<Point>("Point")
+            .field::<i32>("x", mem::offset_of!(Point, x))
+            .field::<i32>("y", mem::offset_of!(Point, y))
+            .note("Like Rectangle, but i32s."),
+        Layout::of::<Direction>("Direction")
+            .note("A Point plus a tag saying which variant it is. The tag has values no variant uses, and None takes one of them."),
+        Layout::of::<Keys>("Keys")
+            .note("Every variant holds a String, next to a tag saying which variant it is. None is another tag value."),
+        Layout::of::<Example>("Example")
+            .note("The variants share the same space, so the size is that of the biggest (String). A String's capacity can never be above isize::MAX, and those impossible capacities say which variant it is instead of a separate tag."),
+        Layout::of::<f64>("f64")
+            .note("Every bit pattern is some f64 (NaNs included), so there is no niche and Option adds a tag, padded out to f64's alignment."),
+        Layout::of::<&String>("&String")
+            .note("References are never null, and that is the niche Option uses: None is stored as the null pointer."),
+        Layout::of::<(i32, &str)>("(i32, &str)")
+            .field::<i32>("0", mem::offset_of!((i32, &str), 0))
+            .field::<&str>("1", mem::offset_of!((i32, &str), 1))
+            .note("The tuple from intro_1::tuples. &str is a pointer and a length, and the i32 is padded to their alignment."),
+        Layout::of::<(u8, u32, u16)>("(u8, u32, u16)")
+            .field::<u8>("0", mem::offset_of!((u8, u32, u16), 0))
+            .field::<u32>("1", mem::offset_of!((u8, u32, u16), 1))
+            .field::<u16>("2", mem::offset_of!((u8, u32, u16), 2))
+            .note("Rust may reorder fields, e.g. putting the u32 first so only one byte is padding. Kept in declaration order, like #[repr(C)] does, it would need 12 bytes."),
+    ]
+}
+
+/// The layouts as a table, with each type's fields underneath it and the
+/// notes at the end.
+pub struct Table<'a>(pub &'a [Layout]);
+
+impl<'a> fmt::Display for Table<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{:<16} {:>5} {:>6} {:>8}  Option<T>", "type", "size", "align", "padding")?;
+        for layout in self.0 {
+            let padding = layout.padding().map_or(String::from("-"), |padding| padding.to_string());
+            let option = if layout.has_niche() {
+                format!("{} (niche)", layout.option_size)
+            } else {
+                format!("{} (+{} for the tag)", layout.option_size, layout.option_size - layout.size)
+            };
+            writeln!(
+                f,
+                "{:<16} {:>5} {:>6} {:>8}  {}",
+                layout.name, layout.size, layout.align, padding, option
+            )?;
+            for field in &layout.fields {
+                writeln!(f, "  .{:<13} offset {:>2}, {} byte(s)", field.name, field.offset, field.size)?;
+            }
+        }
+        for layout in self.0 {
+            write!(f, "\n{}: {}", layout.name, layout.note)?;
+        }
+        Ok(())
+    }
+}
+
+/// Sizes, alignments and field offsets of the types the other lessons use.
+/// The numbers are for the machine this runs on; pointers are 8 bytes on
+/// 64-bit targets.
+pub fn _lesson_types(out: &Output) {
+    // mem::size_of and mem::align_of work on a type instead of a value
+    // (like size_of_val in intro_1::arrays), and mem::offset_of! says how
+    // far into a struct or tuple a field starts.
+    let layouts = layouts();
+    outln!(out, "{}", Table(&layouts));
+}
@@ -0,0 +1,207 @@
+// Lessons and the registry that lists them. Each notes module registers its
+// functions here, so the runner (and anything else that wants to walk the
+// lessons) does not need its own hard-coded list.
+
+use generics;
+use intro_1;
+use layout;
+use output::{self, Output};
+use quiz::Question;
+use source::{Function, Source};
+use traits;
+
+/// A runnable piece of the notes along with what we know about it.
+pub trait Lesson {
+    /// Module the lesson lives in, e.g. `intro_1`.
+    fn module(&self) -> &str;
+
+    /// Short name inside the module, e.g. `ownership`.
+    fn name(&self) -> &str;
+
+    /// One line description for listings.
+    fn title(&self) -> &str;
+
+    /// Topics the lesson covers, used for searching.
+    fn tags(&self) -> &[&str];
+
+    /// Run the lesson, printing to `out`.
+    fn run(&self, out: &Output);
+
+    /// Quiz questions about what the lesson prints.
+    fn questions(&self) -> &[Question] {
+        &[]
+    }
+
+    /// The file the lesson is written in, if we have it.
+    fn source(&self) -> Option<Source> {
+        None
+    }
+
+    /// Name used on the command line, e.g. `intro_1::ownership`.
+    fn full_name(&self) -> String {
+        format!("{}::{}", self.module(), self.name())
+    }
+
+    fn has_tag(&self, tag: &str) -> bool {
+        self.tags().contains(&tag)
+    }
+
+    /// The lesson's own function, found in its source by name. Most notes
+    /// functions start with an underscore, so `_ownership` is tried too.
+    fn function(&self) -> Option<Function<'static>> {
+        let source = self.source()?;
+        source
+            .function(self.name())
+            .or_else(|| source.function(&format!("_{}", self.name())))
+    }
+
+    /// Run the lesson and return what it printed.
+    fn capture(&self) -> String {
+        output::capture(|out| self.run(out))
+    }
+}
+
+/// A lesson backed by one of the notes functions.
+pub struct NotesLesson {
+    module: &'static str,
+    name: &'static str,
+    title: &'static str,
+    tags: &'static [&'static str],
+    source: Option<Source>,
+    questions: &'static [Question],
+    run: fn(&Output),
+}
+
+impl NotesLesson {
+    pub fn new(module: &'static str, name: &'static str, title: &'static str, run: fn(&Output)) -> NotesLesson {
+        NotesLesson {
+            module,
+            name,
+            title,
+            tags: &[],
+            source: None,
+            questions: &[],
+            run,
+        }
+    }
+
+    pub fn tags(mut self, tags: &'static [&'static str]) -> NotesLesson {
+        self.tags = tags;
+        self
+    }
+
+    pub fn source(mut self, source: Source) -> NotesLesson {
+        self.source = Some(source);
+        self
+    }
+
+    pub fn questions(mut self, questions: &'static [Question]) -> NotesLesson {
+        self.questions = questions;
+        self
+    }
+}
+
+impl Lesson for NotesLesson {
+    fn module(&self) -> &str {
+        self.module
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn title(&self) -> &str {
+        self.title
+    }
+
+    fn tags(&self) -> &[&str] {
+        self.tags
+    }
+
+    fn run(&self, out: &Output) {
+        (self.run)(out)
+    }
+
+    fn source(&self) -> Option<Source> {
+        self.source
+    }
+
+    fn questions(&self) -> &[Question] {
+        self.questions
+    }
+}
+
+/// Every known lesson, kept in the order they are meant to be read.
+#[derive(Default)]
+pub struct Registry {
+    lessons: Vec<Box<dyn Lesson>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    /// A registry holding all of the notes in this crate.
+    pub fn builtin() -> Registry {
+        let mut registry = Registry::new();
+        intro_1::register(&mut registry);
+        traits::register(&mut registry);
+        generics::register(&mut registry);
+        layout::register(&mut registry);
+        registry
+    }
+
+    pub fn add<L: Lesson + 'static>(&mut self, lesson: L) {
+        self.lessons.push(Box::new(lesson));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Lesson> {
+        self.lessons.iter().map(|lesson| lesson.as_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.lessons.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lessons.is_empty()
+    }
+
+    /// Look up a lesson by its full `module::name`.
+    pub fn find(&self, full_name: &str) -> Option<&dyn Lesson> {
+        self.iter().find(|lesson| lesson.full_name() == full_name)
+    }
+
+    /// Module names in the order their first lesson was registered.
+    pub fn modules(&self) -> Vec<&str> {
+        let mut modules: Vec<&str> = Vec::new();
+        for lesson in self.iter() {
+            if !modules.contains(&lesson.module()) {
+                modules.push(lesson.module());
+            }
+        }
+        modules
+    }
+
+    pub fn in_module<'a>(&'a self, module: &'a str) -> impl Iterator<Item = &'a dyn Lesson> {
+        self.iter().filter(move |lesson| lesson.module() == module)
+    }
+
+    pub fn with_tag<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a dyn Lesson> {
+        self.iter().filter(move |lesson| lesson.has_tag(tag))
+    }
+
+    /// Full names of lessons that look like what the user meant, used for
+    /// "did you mean" hints. `ownership` suggests `intro_1::ownership`.
+    pub fn suggestions(&self, wanted: &str) -> Vec<String> {
+        let wanted = wanted.rsplit("::").next().unwrap_or(wanted);
+        if wanted.is_empty() {
+            return Vec::new();
+        }
+        self.iter()
+            .filter(|lesson| lesson.name().contains(wanted))
+            .map(|lesson| lesson.full_name())
+            .collect()
+    }
+}
@@ -0,0 +1,30 @@
+// The notes live in this library (one module per set of notes) together
+// with the tooling that works with them. main.rs only handles running it
+// from the command line.
+
+// outln! has to be defined before the modules that use it.
+#[macro_use]
+pub mod output;
+
+pub mod allocations;
+pub mod annotate;
+pub mod area;
+pub mod bench;
+pub mod cli;
+pub mod exercises;
+pub mod export;
+pub mod fuzzy;
+pub mod generics;
+pub mod intro_1;
+pub mod layout;
+pub mod lesson;
+pub mod menu;
+pub mod numeric;
+pub mod progress;
+pub mod quiz;
+pub mod runner;
+pub mod shapes;
+pub mod snapshot;
+pub mod source;
+pub mod tracked;
+pub mod traits;
@@ -0,0 +1,150 @@
+// A text menu for browsing the lessons: pick one by number or by (part of)
+// its name, read what it prints, then come back and pick another, or press
+// n to carry on with the next lesson in reading order.
+
+use std::io::{self, BufRead, Write};
+
+use fuzzy;
+use lesson::{Lesson, Registry};
+use output::Output;
+use runner::{self, Outcome};
+
+const HELP: &str =
+    "Pick a lesson by number or name, n for the next lesson, l to list them again, q to quit.";
+
+/// What a line typed at the menu asks for.
+#[derive(Debug, PartialEq)]
+pub enum Choice {
+    /// Index into the registry.
+    Lesson(usize),
+    Next,
+    List,
+    Quit,
+    /// Several lessons matched a name equally well.
+    Ambiguous(Vec<String>),
+    NotFound(String),
+    Nothing,
+}
+
+/// Work out what `line` means. Numbers are the ones shown in the listing,
+/// which start at 1.
+pub fn choose(registry: &Registry, line: &str) -> Choice {
+    let line = line.trim();
+    match line {
+        "" => return Choice::Nothing,
+        "q" | "quit" | "exit" => return Choice::Quit,
+        "n" | "next" => return Choice::Next,
+        "l" | "list" => return Choice::List,
+        _ => {}
+    }
+
+    if let Ok(number) = line.parse::<usize>() {
+        return if number >= 1 && number <= registry.len() {
+            Choice::Lesson(number - 1)
+        } else {
+            Choice::NotFound(line.to_string())
+        };
+    }
+
+    let names: Vec<String> = registry.iter().map(|lesson| lesson.full_name()).collect();
+    if let Some(index) = names.iter().position(|name| name == line) {
+        return Choice::Lesson(index);
+    }
+    let ranked = fuzzy::rank(line, names.iter().map(|name| name.as_str()));
+    match ranked.first() {
+        None => Choice::NotFound(line.to_string()),
+        Some(&(best, _)) => {
+            let tied: Vec<&str> = ranked
+                .iter()
+                .take_while(|&&(score, _)| score == best)
+                .map(|&(_, name)| name)
+                .collect();
+            if tied.len() == 1 {
+                Choice::Lesson(names.iter().position(|name| name == tied[0]).unwrap())
+            } else {
+                Choice::Ambiguous(tied.iter().map(|name| name.to_string()).collect())
+            }
+        }
+    }
+}
+
+/// Print the lessons grouped by module, numbered for picking.
+pub fn list(registry: &Registry, out: &mut dyn Write) -> io::Result<()> {
+    let width = registry.iter().map(|lesson| lesson.name().len()).max().unwrap_or(0);
+    let mut number = 1;
+    for module in registry.modules() {
+        writeln!(out, "{}", module)?;
+        for lesson in registry.in_module(module) {
+            writeln!(
+                out,
+                "  {:>2}. {:<width$}  {}",
+                number,
+                lesson.name(),
+                lesson.title(),
+                width = width
+            )?;
+            number += 1;
+        }
+    }
+    Ok(())
+}
+
+/// Show the menu until the user quits or the input runs out. Returns the
+/// full names of the lessons that ran without panicking.
+pub fn run(registry: &Registry, input: &mut dyn BufRead, out: &mut dyn Write) -> io::Result<Vec<String>> {
+    let lessons: Vec<&dyn Lesson> = registry.iter().collect();
+    // the lesson we ran last, so n knows where to go next.
+    let mut current: Option<usize> = None;
+    let mut ran = Vec::new();
+
+    list(registry, out)?;
+    writeln!(out, "\n{}", HELP)?;
+    loop {
+        write!(out, "> ")?;
+        out.flush()?;
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            writeln!(out)?;
+            return Ok(ran);
+        }
+
+        let index = match choose(registry, &line) {
+            Choice::Lesson(index) => index,
+            Choice::Next => match current {
+                None => 0,
+                Some(index) if index + 1 < lessons.len() => index + 1,
+                Some(_) => {
+                    writeln!(out, "That was the last lesson. Pick another one or q to quit.")?;
+                    continue;
+                }
+            },
+            Choice::List => {
+                list(registry, out)?;
+                continue;
+            }
+            Choice::Quit => return Ok(ran),
+            Choice::Ambiguous(names) => {
+                writeln!(out, "That could be any of: {}", names.join(", "))?;
+                continue;
+            }
+            Choice::NotFound(what) => {
+                writeln!(out, "No lesson matches `{}`. {}", what, HELP)?;
+                continue;
+            }
+            Choice::Nothing => continue,
+        };
+
+        let lesson = lessons[index];
+        writeln!(out, "\n== {}: {} ==", lesson.full_name(), lesson.title())?;
+        let report = runner::run_lesson(lesson, &Output::new(&mut *out));
+        match report.outcome {
+            Outcome::Passed => ran.push(report.lesson),
+            Outcome::Panicked(message) => writeln!(out, "(the lesson panicked: {})", message)?,
+        }
+        current = Some(index);
+        match lessons.get(index + 1) {
+            Some(next) => writeln!(out, "\n-- n: next lesson is {} --", next.full_name())?,
+            None => writeln!(out, "\n-- that was the last lesson --")?,
+        }
+    }
+}
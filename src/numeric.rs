@@ -0,0 +1,166 @@
+// Numbers that generic code can do geometry with. std's operator traits
+// (Mul and friends) are enough for a rectangle's area, but a circle also
+// needs π, and `3.141 * radius` does not compile when radius is some T.
+// Numeric adds the constants and conversions that generic code needs, for
+// f32, f64 and the Fixed point type below.
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+pub trait Numeric:
+    Copy
+    + PartialOrd
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const PI: Self;
+
+    /// The nearest value to `value` this type can hold.
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+}
+
+impl Numeric for f32 {
+    const ZERO: f32 = 0.0;
+    const ONE: f32 = 1.0;
+    const PI: f32 = ::std::f32::consts::PI;
+
+    fn from_f64(value: f64) -> f32 {
+        value as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+}
+
+impl Numeric for f64 {
+    const ZERO: f64 = 0.0;
+    const ONE: f64 = 1.0;
+    const PI: f64 = ::std::f64::consts::PI;
+
+    fn from_f64(value: f64) -> f64 {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+/// A fixed point number with 32 bits after the binary point, so steps of
+/// about 2.3e-10, up to about ±2.1 billion. Arithmetic saturates instead of
+/// overflowing.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed(i64);
+
+impl Fixed {
+    pub const FRACTION_BITS: u32 = 32;
+    const SCALE: f64 = (1u64 << Fixed::FRACTION_BITS) as f64;
+
+    pub fn from_bits(bits: i64) -> Fixed {
+        Fixed(bits)
+    }
+
+    pub fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    pub fn from_int(value: i32) -> Fixed {
+        Fixed(i64::from(value) << Fixed::FRACTION_BITS)
+    }
+
+    /// The smallest step between two Fixed values.
+    pub fn epsilon() -> f64 {
+        1.0 / Fixed::SCALE
+    }
+}
+
+impl Numeric for Fixed {
+    const ZERO: Fixed = Fixed(0);
+    const ONE: Fixed = Fixed(1 << Fixed::FRACTION_BITS);
+    // π * 2^32, rounded.
+    const PI: Fixed = Fixed(13_493_037_705);
+
+    fn from_f64(value: f64) -> Fixed {
+        // `as` saturates, and turns NaN into 0.
+        Fixed((value * Fixed::SCALE).round() as i64)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.0 as f64 / Fixed::SCALE
+    }
+}
+
+impl Add for Fixed {
+    type Output = Fixed;
+
+    fn add(self, other: Fixed) -> Fixed {
+        Fixed(self.0.saturating_add(other.0))
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Fixed;
+
+    fn sub(self, other: Fixed) -> Fixed {
+        Fixed(self.0.saturating_sub(other.0))
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Fixed;
+
+    fn mul(self, other: Fixed) -> Fixed {
+        // the product of two fixed point numbers has twice the fraction
+        // bits, so shift half of them back out (rounding to nearest).
+        let product = i128::from(self.0) * i128::from(other.0);
+        let rounded = (product + (1 << (Fixed::FRACTION_BITS - 1))) >> Fixed::FRACTION_BITS;
+        Fixed(saturate(rounded))
+    }
+}
+
+impl Div for Fixed {
+    type Output = Fixed;
+
+    /// Panics when dividing by zero, like integers do.
+    fn div(self, other: Fixed) -> Fixed {
+        let quotient = (i128::from(self.0) << Fixed::FRACTION_BITS) / i128::from(other.0);
+        Fixed(saturate(quotient))
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Fixed;
+
+    fn neg(self) -> Fixed {
+        Fixed(self.0.saturating_neg())
+    }
+}
+
+fn saturate(value: i128) -> i64 {
+    if value > i128::from(i64::MAX) {
+        i64::MAX
+    } else if value < i128::from(i64::MIN) {
+        i64::MIN
+    } else {
+        value as i64
+    }
+}
+
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
+
+impl fmt::Debug for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Fixed({})", self.to_f64())
+    }
+}
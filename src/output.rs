@@ -0,0 +1,83 @@
+// Where lessons print to. Lessons used to call println! directly, which meant
+// their output could only ever go to the terminal. Now they are handed an
+// Output and print with outln!, so the same lesson can write to stdout, a
+// test buffer or a file.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::io::Write;
+
+/// Print a line to an Output, the same way println! prints to stdout. The
+/// file and line of the call are passed along for the annotated view.
+///
+/// ```ignore
+/// outln!(out, "{} is less than 10", num);
+/// ```
+#[macro_export]
+macro_rules! outln {
+    ($out:expr) => {
+        $out.write_line(file!(), line!(), format_args!(""))
+    };
+    ($out:expr, $($arg:tt)*) => {
+        $out.write_line(file!(), line!(), format_args!($($arg)*))
+    };
+}
+
+/// Something a lesson printed, and the outln! call that printed it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Printed {
+    pub file: &'static str,
+    pub line: u32,
+    pub text: String,
+}
+
+/// A writer shared by everything a lesson prints from, including nested
+/// functions and Drop impls (which is why it only needs `&self`).
+pub struct Output<'a> {
+    writer: RefCell<&'a mut dyn Write>,
+    /// Only kept when recording, see Output::recording.
+    printed: RefCell<Option<Vec<Printed>>>,
+}
+
+impl<'a> Output<'a> {
+    pub fn new(writer: &'a mut dyn Write) -> Output<'a> {
+        Output {
+            writer: RefCell::new(writer),
+            printed: RefCell::new(None),
+        }
+    }
+
+    /// An Output that also remembers where each line was printed from.
+    pub fn recording(writer: &'a mut dyn Write) -> Output<'a> {
+        Output {
+            writer: RefCell::new(writer),
+            printed: RefCell::new(Some(Vec::new())),
+        }
+    }
+
+    /// Write one line. Like println!, this panics if the writer fails.
+    pub fn write_line(&self, file: &'static str, line: u32, args: fmt::Arguments) {
+        let text = args.to_string();
+        let mut writer = self.writer.borrow_mut();
+        writeln!(writer, "{}", text).expect("failed to write lesson output");
+
+        if let Some(ref mut printed) = *self.printed.borrow_mut() {
+            printed.push(Printed { file, line, text });
+        }
+    }
+
+    /// Everything recorded so far (empty unless this Output is recording).
+    pub fn take_printed(&self) -> Vec<Printed> {
+        match *self.printed.borrow_mut() {
+            Some(ref mut printed) => printed.split_off(0),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// Run `f` with an Output that collects everything printed into a String.
+pub fn capture<F: FnOnce(&Output)>(f: F) -> String {
+    let mut buffer = Vec::new();
+    f(&Output::new(&mut buffer));
+    String::from_utf8(buffer).expect("lesson output was not utf-8")
+}
@@ -0,0 +1,367 @@
+// Remembers which lessons someone has run and which quizzes they passed, in
+// a small text file under their data directory, e.g.
+// ~/.local/share/rust_playground/progress.txt:
+//
+//     rust_playground progress v1
+//     run	intro_1::ownership	1760781600
+//     quiz	intro_1::control_flow	2/2	1760781660
+//
+// The first line says which version of the format the file uses. A newer
+// file is never rewritten by an older program, and lines this version does
+// not know about are kept as they are.
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use lesson::{Lesson, Registry};
+use quiz::Score;
+
+/// Version of the file format written by this program.
+pub const VERSION: u32 = 1;
+
+const HEADER: &str = "rust_playground progress v";
+
+/// Set this to keep progress somewhere other than the usual data directory.
+pub const DIR_VARIABLE: &str = "RUST_PLAYGROUND_DATA_DIR";
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// The file was written by a newer version of the program.
+    NewerVersion(u32),
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Io(ref err) => write!(f, "{}", err),
+            Error::NewerVersion(version) => write!(
+                f,
+                "progress file is version {} but this program only understands up to {}; \
+                 leaving it alone",
+                version, VERSION
+            ),
+            Error::Parse { line, ref message } => {
+                write!(f, "progress file line {}: {}", line, message)
+            }
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        Error::Io(err)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub lesson: String,
+    /// Seconds since the unix epoch.
+    pub at: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuizResult {
+    pub lesson: String,
+    pub score: Score,
+    pub at: u64,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Progress {
+    pub runs: Vec<Run>,
+    pub quizzes: Vec<QuizResult>,
+    /// Lines we did not understand, written back untouched.
+    unknown: Vec<String>,
+}
+
+/// Where progress is kept: $RUST_PLAYGROUND_DATA_DIR if set, otherwise the
+/// platform's per-user data directory.
+pub fn default_path() -> Option<PathBuf> {
+    let dir = match env::var_os(DIR_VARIABLE) {
+        Some(dir) => PathBuf::from(dir),
+        None => data_dir()?.join("rust_playground"),
+    };
+    Some(dir.join("progress.txt"))
+}
+
+#[cfg(windows)]
+fn data_dir() -> Option<PathBuf> {
+    env::var_os("APPDATA").map(PathBuf::from)
+}
+
+#[cfg(target_os = "macos")]
+fn data_dir() -> Option<PathBuf> {
+    env::var_os("HOME").map(|home| Path::new(&home).join("Library/Application Support"))
+}
+
+#[cfg(not(any(windows, target_os = "macos")))]
+fn data_dir() -> Option<PathBuf> {
+    match env::var_os("XDG_DATA_HOME") {
+        Some(ref dir) if !dir.is_empty() => Some(PathBuf::from(dir)),
+        _ => env::var_os("HOME").map(|home| Path::new(&home).join(".local/share")),
+    }
+}
+
+/// Seconds since the unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+/// `at` as "2026-10-18 09:30 UTC".
+pub fn format_time(at: u64) -> String {
+    // days to a civil date, from Howard Hinnant's date algorithms.
+    let days = (at / 86_400) as i64;
+    let seconds = at % 86_400;
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        seconds / 3_600,
+        seconds % 3_600 / 60
+    )
+}
+
+impl Progress {
+    pub fn new() -> Progress {
+        Progress::default()
+    }
+
+    /// Read progress from `path`. A file that does not exist yet is the same
+    /// as no progress.
+    pub fn load(path: &Path) -> Result<Progress, Error> {
+        match fs::read_to_string(path) {
+            Ok(text) => Progress::parse(&text),
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => Ok(Progress::new()),
+            Err(err) => Err(Error::Io(err)),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Progress, Error> {
+        let mut lines = text.lines().enumerate();
+        let version = match lines.next() {
+            None => return Ok(Progress::new()),
+            Some((_, header)) => match header
+                .strip_prefix(HEADER)
+                .and_then(|version| version.trim().parse::<u32>().ok())
+            {
+                Some(version) => version,
+                None => return Err(parse_error(0, "missing the progress file header")),
+            },
+        };
+        if version > VERSION {
+            return Err(Error::NewerVersion(version));
+        }
+        // version 1 is the first format, so there is nothing to migrate from
+        // yet. Older formats get converted here as they appear.
+
+        let mut progress = Progress::new();
+        for (index, line) in lines {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = line.split('\t').collect();
+            match (fields[0], fields.len()) {
+                // files from before runs and quizzes were merged can have
+                // several lines per lesson; they shrink to one the next time
+                // they are saved.
+                ("run", 3) => progress.record_run(fields[1], parse_number(index, fields[2])?),
+                ("quiz", 4) => {
+                    let mut score = fields[2].splitn(2, '/');
+                    let correct = parse_number(index, score.next().unwrap_or(""))?;
+                    let asked = parse_number(index, score.next().unwrap_or(""))?;
+                    let score = Score {
+                        correct: correct as usize,
+                        asked: asked as usize,
+                    };
+                    progress.record_quiz(fields[1], score, parse_number(index, fields[3])?);
+                }
+                ("run", _) | ("quiz", _) => {
+                    return Err(parse_error(index, &format!("wrong number of fields in `{}`", line)))
+                }
+                _ => progress.unknown.push(line.to_string()),
+            }
+        }
+        Ok(progress)
+    }
+
+    /// Write the progress out, replacing the file in one step so a crash
+    /// halfway through cannot leave a broken file behind. Refuses to
+    /// overwrite a file from a newer version.
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        if let Err(Error::NewerVersion(version)) = Progress::load(path) {
+            return Err(Error::NewerVersion(version));
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let temporary = path.with_extension("txt.tmp");
+        fs::write(&temporary, self.to_string())?;
+        fs::rename(&temporary, path)?;
+        Ok(())
+    }
+
+    /// Only the latest run of each lesson is kept, so the file does not
+    /// grow every time a lesson runs.
+    pub fn record_run(&mut self, lesson: &str, at: u64) {
+        match self.runs.iter_mut().find(|run| run.lesson == lesson) {
+            Some(run) => run.at = run.at.max(at),
+            None => self.runs.push(Run {
+                lesson: lesson.to_string(),
+                at,
+            }),
+        }
+    }
+
+    /// One result is kept per lesson: the latest attempt until the quiz is
+    /// passed, and from then on the first pass, so quiz_passed() keeps its
+    /// date.
+    pub fn record_quiz(&mut self, lesson: &str, score: Score, at: u64) {
+        match self.quizzes.iter_mut().find(|quiz| quiz.lesson == lesson) {
+            Some(quiz) => {
+                let replace = if quiz.score.passed() {
+                    score.passed() && at < quiz.at
+                } else {
+                    score.passed() || at >= quiz.at
+                };
+                if replace {
+                    quiz.score = score;
+                    quiz.at = at;
+                }
+            }
+            None => self.quizzes.push(QuizResult {
+                lesson: lesson.to_string(),
+                score,
+                at,
+            }),
+        }
+    }
+
+    /// When `lesson` was last run.
+    pub fn last_run(&self, lesson: &str) -> Option<u64> {
+        self.runs.iter().find(|run| run.lesson == lesson).map(|run| run.at)
+    }
+
+    /// When the quiz for `lesson` was first passed.
+    pub fn quiz_passed(&self, lesson: &str) -> Option<u64> {
+        self.quizzes
+            .iter()
+            .find(|quiz| quiz.lesson == lesson && quiz.score.passed())
+            .map(|quiz| quiz.at)
+    }
+
+    /// The first lesson, in reading order, that has not been run yet, or
+    /// failing that one whose quiz has not been passed.
+    pub fn next_lesson<'a>(&self, registry: &'a Registry) -> Option<&'a dyn Lesson> {
+        registry
+            .iter()
+            .find(|lesson| self.last_run(&lesson.full_name()).is_none())
+            .or_else(|| {
+                registry.iter().find(|lesson| {
+                    !lesson.questions().is_empty() && self.quiz_passed(&lesson.full_name()).is_none()
+                })
+            })
+    }
+
+    /// A per module report of what has been done, ending with a suggestion
+    /// for what to do next.
+    pub fn report(&self, registry: &Registry) -> String {
+        let mut text = String::new();
+        for module in registry.modules() {
+            let lessons: Vec<&dyn Lesson> = registry.in_module(module).collect();
+            let run = lessons
+                .iter()
+                .filter(|lesson| self.last_run(&lesson.full_name()).is_some())
+                .count();
+            let quizzes = lessons.iter().filter(|lesson| !lesson.questions().is_empty()).count();
+            let passed = lessons
+                .iter()
+                .filter(|lesson| self.quiz_passed(&lesson.full_name()).is_some())
+                .count();
+            text.push_str(&format!(
+                "{}: {}/{} lessons run, {}/{} quizzes passed ({}%)\n",
+                module,
+                run,
+                lessons.len(),
+                passed,
+                quizzes,
+                run * 100 / lessons.len()
+            ));
+
+            for lesson in lessons {
+                let name = lesson.full_name();
+                let mut line = format!(
+                    "  [{}] {}",
+                    if self.last_run(&name).is_some() { "x" } else { " " },
+                    lesson.name()
+                );
+                if let Some(at) = self.last_run(&name) {
+                    line.push_str(&format!(", last run {}", format_time(at)));
+                }
+                if let Some(at) = self.quiz_passed(&name) {
+                    line.push_str(&format!(", quiz passed {}", format_time(at)));
+                }
+                text.push_str(&line);
+                text.push('\n');
+            }
+        }
+        match self.next_lesson(registry) {
+            Some(lesson) => text.push_str(&format!("next: {} ({})\n", lesson.full_name(), lesson.title())),
+            None => text.push_str("next: nothing, every lesson is done!\n"),
+        }
+        text
+    }
+}
+
+impl fmt::Display for Progress {
+    /// The file format.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}{}", HEADER, VERSION)?;
+        for run in &self.runs {
+            writeln!(f, "run\t{}\t{}", run.lesson, run.at)?;
+        }
+        for quiz in &self.quizzes {
+            writeln!(
+                f,
+                "quiz\t{}\t{}/{}\t{}",
+                quiz.lesson, quiz.score.correct, quiz.score.asked, quiz.at
+            )?;
+        }
+        for line in &self.unknown {
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_number(index: usize, text: &str) -> Result<u64, Error> {
+    text.parse()
+        .map_err(|_| parse_error(index, &format!("`{}` is not a number", text)))
+}
+
+/// `index` counts from 0, file lines from 1.
+fn parse_error(index: usize, message: &str) -> Error {
+    Error::Parse {
+        line: index + 1,
+        message: message.to_string(),
+    }
+}
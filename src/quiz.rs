@@ -0,0 +1,139 @@
+// Quizzes about what the lessons print. Questions are attached to lessons,
+// and instead of hard-coding the answers they say where in the output the
+// answer is, so the answer is always what the code really prints.
+
+use std::io::{self, BufRead, Write};
+
+use lesson::Lesson;
+use output::Output;
+use runner::{self, Outcome};
+
+/// Where to find the answer in a lesson's output.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Answer {
+    /// The nth line (starting at 0).
+    Line(usize),
+    /// The line printed right after the first line equal to this one.
+    LineAfter(&'static str),
+    /// The rest of the first line that starts with this text.
+    After(&'static str),
+    /// A whitespace separated word (starting at 0) of the first line that
+    /// starts with `line`.
+    Word { line: &'static str, index: usize },
+}
+
+impl Answer {
+    /// Look the answer up in `output`, None if it is not there (which means
+    /// the lesson changed and the question needs updating).
+    pub fn find(&self, output: &str) -> Option<String> {
+        let mut lines = output.lines();
+        let found = match *self {
+            Answer::Line(n) => lines.nth(n),
+            Answer::LineAfter(before) => {
+                lines.by_ref().find(|line| *line == before)?;
+                lines.next()
+            }
+            Answer::After(prefix) => lines
+                .find(|line| line.starts_with(prefix))
+                .map(|line| &line[prefix.len()..]),
+            Answer::Word { line, index } => lines
+                .find(|l| l.starts_with(line))
+                .and_then(|l| l.split_whitespace().nth(index)),
+        };
+        found.map(|answer| answer.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Question {
+    pub prompt: &'static str,
+    pub answer: Answer,
+}
+
+/// Whether `given` is the same answer as `expected`. Case, whitespace and
+/// surrounding quotes are ignored, and characters that cannot be typed can
+/// be given escaped (`\u{18}`).
+pub fn is_correct(expected: &str, given: &str) -> bool {
+    fn normalize(text: &str) -> String {
+        let text = text.trim();
+        let unquoted = if text.len() >= 2
+            && (text.starts_with('"') && text.ends_with('"')
+                || text.starts_with('\'') && text.ends_with('\''))
+        {
+            &text[1..text.len() - 1]
+        } else {
+            text
+        };
+        unquoted
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase()
+    }
+
+    let given = normalize(given);
+    !given.is_empty()
+        && (given == normalize(expected) || given == normalize(&expected.escape_default().to_string()))
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Score {
+    pub correct: usize,
+    pub asked: usize,
+}
+
+impl Score {
+    /// Every question was answered correctly (and there was at least one).
+    pub fn passed(&self) -> bool {
+        self.asked > 0 && self.correct == self.asked
+    }
+}
+
+/// Ask every question attached to `lesson`, reading answers from `input`.
+/// The lesson is run first so the answers come from its real output. None
+/// when the input ends before every question is answered, since there is
+/// no score to give.
+pub fn ask(lesson: &dyn Lesson, input: &mut dyn BufRead, out: &mut dyn Write) -> io::Result<Option<Score>> {
+    let mut score = Score::default();
+    if lesson.questions().is_empty() {
+        return Ok(Some(score));
+    }
+
+    let mut buffer = Vec::new();
+    let report = runner::run_lesson(lesson, &Output::new(&mut buffer));
+    if let Outcome::Panicked(message) = report.outcome {
+        writeln!(out, "skipping {}, the lesson panicked: {}", lesson.full_name(), message)?;
+        return Ok(Some(score));
+    }
+    let output = String::from_utf8_lossy(&buffer);
+
+    writeln!(out, "== {}: {} ==", lesson.full_name(), lesson.title())?;
+    for question in lesson.questions() {
+        let expected = match question.answer.find(&output) {
+            Some(expected) => expected,
+            None => {
+                writeln!(out, "(skipping \"{}\", its answer is no longer in the output)", question.prompt)?;
+                continue;
+            }
+        };
+
+        writeln!(out, "{}", question.prompt)?;
+        write!(out, "> ")?;
+        out.flush()?;
+        let mut given = String::new();
+        if input.read_line(&mut given)? == 0 {
+            writeln!(out)?;
+            return Ok(None);
+        }
+
+        score.asked += 1;
+        if is_correct(&expected, &given) {
+            score.correct += 1;
+            writeln!(out, "correct!")?;
+        } else {
+            writeln!(out, "not quite, it prints: {}", expected.escape_debug())?;
+        }
+    }
+    writeln!(out, "score: {}/{}\n", score.correct, score.asked)?;
+    Ok(Some(score))
+}
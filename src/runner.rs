@@ -0,0 +1,136 @@
+// Runs lessons one at a time, catching panics so one broken lesson (like
+// casting_and_lets_and_result when res/test_result.txt is missing) does not
+// stop the rest, and keeps a report of how each one went.
+
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use allocations::{self, Stats};
+use lesson::Lesson;
+use output::Output;
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Passed,
+    /// The lesson panicked; holds the panic message.
+    Panicked(String),
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub lesson: String,
+    pub outcome: Outcome,
+    pub duration: Duration,
+    /// What the lesson allocated, when allocations are being counted (see
+    /// allocations.rs). This includes formatting what it printed.
+    pub allocations: Option<Stats>,
+}
+
+impl Report {
+    pub fn passed(&self) -> bool {
+        self.outcome == Outcome::Passed
+    }
+}
+
+/// Run a single lesson, catching a panic instead of letting it unwind
+/// through the caller.
+pub fn run_lesson(lesson: &dyn Lesson, out: &Output) -> Report {
+    let start = Instant::now();
+    // Output is not UnwindSafe because of its RefCell, but a lesson that
+    // panics halfway through a line only leaves that line unfinished.
+    let (result, stats) =
+        allocations::measure(|| panic::catch_unwind(AssertUnwindSafe(|| lesson.run(out))));
+    let duration = start.elapsed();
+
+    let outcome = match result {
+        Ok(()) => Outcome::Passed,
+        Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+    };
+    Report {
+        lesson: lesson.full_name(),
+        outcome,
+        duration,
+        allocations: if allocations::is_counting() { Some(stats) } else { None },
+    }
+}
+
+/// Run every lesson in turn, carrying on after any that panic.
+pub fn run_all<'a, I>(lessons: I, out: &Output) -> Vec<Report>
+where
+    I: IntoIterator<Item = &'a dyn Lesson>,
+{
+    lessons
+        .into_iter()
+        .map(|lesson| run_lesson(lesson, out))
+        .collect()
+}
+
+/// panic! payloads are a &str for literal messages and a String when the
+/// message was formatted.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("(panic without a message)")
+    }
+}
+
+/// A table of how each lesson went, ending with a totals line.
+pub struct Summary<'a>(pub &'a [Report]);
+
+impl<'a> Summary<'a> {
+    pub fn failed(&self) -> usize {
+        self.0.iter().filter(|report| !report.passed()).count()
+    }
+}
+
+impl<'a> fmt::Display for Summary<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // allocation columns only when they were counted.
+        let counted = self.0.iter().any(|report| report.allocations.is_some());
+        if counted {
+            writeln!(
+                f,
+                "{:<40} {:<9} {:>10} {:>7} {:>8} {:>9}  message",
+                "lesson", "result", "time", "allocs", "reallocs", "bytes"
+            )?;
+        } else {
+            writeln!(f, "{:<40} {:<9} {:>10}  message", "lesson", "result", "time")?;
+        }
+        for report in self.0 {
+            let (result, message) = match report.outcome {
+                Outcome::Passed => ("passed", ""),
+                // only the first line, so the table stays a table.
+                Outcome::Panicked(ref message) => {
+                    ("panicked", message.lines().next().unwrap_or(""))
+                }
+            };
+            let time = format!("{:.2?}", report.duration);
+            let row = match report.allocations {
+                Some(stats) if counted => format!(
+                    "{:<40} {:<9} {:>10} {:>7} {:>8} {:>9}  {}",
+                    report.lesson,
+                    result,
+                    time,
+                    stats.allocations,
+                    stats.reallocations,
+                    stats.bytes,
+                    message
+                ),
+                _ => format!("{:<40} {:<9} {:>10}  {}", report.lesson, result, time, message),
+            };
+            writeln!(f, "{}", row.trim_end())?;
+        }
+        write!(
+            f,
+            "{} lesson(s): {} passed, {} panicked",
+            self.0.len(),
+            self.0.len() - self.failed(),
+            self.failed()
+        )
+    }
+}
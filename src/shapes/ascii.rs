@@ -0,0 +1,159 @@
+// Draws placed shapes with characters, for the terminal where there are no
+// graphics. Every character cell is filled in when the middle of the cell
+// is inside a shape. Shapes drawn later go on top of earlier ones.
+//
+// Characters are about twice as tall as they are wide, so a cell covers
+// `scale` units across and twice that down, which keeps circles round:
+//
+//      ######
+//     ########
+//     ########
+//      ######
+
+use std::error;
+use std::fmt;
+
+use shapes::geometry::Bounds;
+use shapes::{AnyShape, Placed, Point};
+
+/// How tall a character is compared to its width.
+const ASPECT: f64 = 2.0;
+
+/// The most columns, and the most rows, Canvas::around will make. More
+/// would not fit on a terminal anyway, and a tiny scale could otherwise ask
+/// for more memory than there is.
+pub const MAX_CELLS: usize = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CanvasError {
+    /// The shapes need more than MAX_CELLS columns or rows at this scale.
+    TooBig { columns: f64, rows: f64 },
+}
+
+impl fmt::Display for CanvasError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CanvasError::TooBig { columns, rows } => write!(
+                f,
+                "the drawing would be {} x {} characters, more than {} x {}: use a bigger scale",
+                columns, rows, MAX_CELLS, MAX_CELLS
+            ),
+        }
+    }
+}
+
+impl error::Error for CanvasError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    Filled,
+    /// Only the cells on the edge of the shape.
+    Outline,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Canvas {
+    /// Where the top left corner of the canvas is.
+    origin: Point,
+    /// Units per column.
+    scale: f64,
+    columns: usize,
+    rows: usize,
+    cells: Vec<char>,
+}
+
+impl Canvas {
+    /// A blank canvas with its top left corner at (0, 0).
+    pub fn new(columns: usize, rows: usize, scale: f64) -> Canvas {
+        Canvas {
+            origin: Point::new(0.0, 0.0),
+            scale,
+            columns,
+            rows,
+            cells: vec![' '; columns * rows],
+        }
+    }
+
+    /// A blank canvas just big enough for `placed`, as long as that is no
+    /// more than MAX_CELLS each way.
+    pub fn around(placed: &[Placed], scale: f64) -> Result<Canvas, CanvasError> {
+        let bounds = match Bounds::around(placed) {
+            Some(bounds) => bounds,
+            None => return Ok(Canvas::new(0, 0, scale)),
+        };
+        let columns = (bounds.width() / scale).ceil();
+        let rows = (bounds.height() / (scale * ASPECT)).ceil();
+        // checked as floats, before `as usize` turns infinity into usize::MAX.
+        if !(columns <= MAX_CELLS as f64 && rows <= MAX_CELLS as f64) {
+            return Err(CanvasError::TooBig { columns, rows });
+        }
+        let mut canvas = Canvas::new(columns as usize, rows as usize, scale);
+        canvas.origin = bounds.min;
+        Ok(canvas)
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn get(&self, column: usize, row: usize) -> Option<char> {
+        if column < self.columns && row < self.rows {
+            Some(self.cells[row * self.columns + column])
+        } else {
+            None
+        }
+    }
+
+    /// Draw `placed` with `brush` on top of what is already there.
+    pub fn draw(&mut self, placed: &Placed, mode: Mode, brush: char) {
+        let (width, height) = (self.scale, self.scale * ASPECT);
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                let middle = Point::new(
+                    self.origin.x + (column as f64 + 0.5) * width,
+                    self.origin.y + (row as f64 + 0.5) * height,
+                );
+                if !placed.contains(middle) {
+                    continue;
+                }
+                // on the edge when a neighbouring cell is not covered.
+                let edge = mode == Mode::Outline
+                    && [(-width, 0.0), (width, 0.0), (0.0, -height), (0.0, height)]
+                        .iter()
+                        .any(|&(dx, dy)| !placed.contains(Point::new(middle.x + dx, middle.y + dy)));
+                if mode == Mode::Filled || edge {
+                    self.cells[row * self.columns + column] = brush;
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Canvas {
+    /// One line per row, without trailing spaces.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.cells.chunks(self.columns.max(1)) {
+            let line: String = row.iter().collect();
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+/// Brushes given to shapes in turn by render(), so overlapping shapes can
+/// be told apart.
+pub const BRUSHES: [char; 6] = ['#', '*', '+', 'o', '%', '@'];
+
+/// `shapes` in a row on a canvas of their own.
+pub fn render(shapes: &[AnyShape], scale: f64, mode: Mode) -> Result<String, CanvasError> {
+    let placed = Placed::in_a_row(shapes, scale);
+    let mut canvas = Canvas::around(&placed, scale)?;
+    for (index, shape) in placed.iter().enumerate() {
+        canvas.draw(shape, mode, BRUSHES[index % BRUSHES.len()]);
+    }
+    Ok(canvas.to_string())
+}
@@ -0,0 +1,208 @@
+// Questions about where placed shapes are, for laying things out: the box
+// around a shape, whether a point is inside it, and whether (and by how
+// much) two shapes overlap.
+//
+// Shapes are closed, so their edges count as inside: two rectangles that
+// share an edge intersect, with an overlap area of 0. Intersections and
+// overlap areas are worked out for rectangles, squares and circles.
+// Triangles only have bounds and contains() so far, and pairs with a
+// triangle in them give None.
+
+use std::f64::consts::PI;
+
+use shapes::{AnyShape, Placed, Point};
+
+/// An axis-aligned box, from its smallest to its largest corner.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    pub fn new(min: Point, max: Point) -> Bounds {
+        Bounds { min, max }
+    }
+
+    /// The box around all of `placed`, or None when there are none.
+    pub fn around(placed: &[Placed]) -> Option<Bounds> {
+        placed
+            .iter()
+            .map(Placed::bounds)
+            .fold(None, |all, bounds| match all {
+                Some(all) => Some(bounds.union(&all)),
+                None => Some(bounds),
+            })
+    }
+
+    pub fn width(&self) -> f64 {
+        self.max.x - self.min.x
+    }
+
+    pub fn height(&self) -> f64 {
+        self.max.y - self.min.y
+    }
+
+    pub fn area(&self) -> f64 {
+        self.width() * self.height()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= self.min.x && point.x <= self.max.x && point.y >= self.min.y && point.y <= self.max.y
+    }
+
+    pub fn intersects(&self, other: &Bounds) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The box both boxes cover, or None when they do not meet.
+    pub fn intersection(&self, other: &Bounds) -> Option<Bounds> {
+        let min = Point::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Point::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+        if min.x <= max.x && min.y <= max.y {
+            Some(Bounds::new(min, max))
+        } else {
+            None
+        }
+    }
+
+    /// The smallest box around both boxes.
+    pub fn union(&self, other: &Bounds) -> Bounds {
+        Bounds::new(
+            Point::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            Point::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        )
+    }
+}
+
+/// The two kinds of shape intersections are worked out for. Squares are
+/// rectangles here.
+enum Simple {
+    Rectangle(Bounds),
+    Circle { centre: Point, radius: f64 },
+}
+
+impl Placed {
+    /// The box around the shape, where it is placed.
+    pub fn bounds(&self) -> Bounds {
+        let (min, max) = self.shape.extent();
+        Bounds::new(
+            Point::new(self.at.x + min.x, self.at.y + min.y),
+            Point::new(self.at.x + max.x, self.at.y + max.y),
+        )
+    }
+
+    /// Whether `point` is inside the shape or on its edge.
+    pub fn contains(&self, point: Point) -> bool {
+        let (x, y) = (point.x - self.at.x, point.y - self.at.y);
+        match self.shape {
+            AnyShape::Triangle(ref t) => {
+                // inside when the point is on the same side of all three edges.
+                // A flat triangle has no inside, and every point on its line
+                // (or, with all three corners the same, every point at all)
+                // would pass that test.
+                let side = |a: Point, b: Point| (b.x - a.x) * (y - a.y) - (b.y - a.y) * (x - a.x);
+                let flat = (t.b.x - t.a.x) * (t.c.y - t.a.y) == (t.b.y - t.a.y) * (t.c.x - t.a.x);
+                let sides = [side(t.a, t.b), side(t.b, t.c), side(t.c, t.a)];
+                !flat && (sides.iter().all(|&s| s >= 0.0) || sides.iter().all(|&s| s <= 0.0))
+            }
+            AnyShape::Circle(ref c) => x * x + y * y <= c.radius * c.radius,
+            AnyShape::Rectangle(_) | AnyShape::Square(_) => self.bounds().contains(point),
+        }
+    }
+
+    /// Whether the two shapes overlap or touch. None when either is a
+    /// triangle.
+    pub fn intersects(&self, other: &Placed) -> Option<bool> {
+        Some(match (self.simple()?, other.simple()?) {
+            (Simple::Rectangle(a), Simple::Rectangle(b)) => a.intersects(&b),
+            (Simple::Circle { centre: a, radius: ra }, Simple::Circle { centre: b, radius: rb }) => {
+                a.distance(b) <= ra + rb
+            }
+            (Simple::Rectangle(bounds), Simple::Circle { centre, radius })
+            | (Simple::Circle { centre, radius }, Simple::Rectangle(bounds)) => {
+                // the point of the rectangle nearest the centre.
+                let nearest = Point::new(
+                    centre.x.max(bounds.min.x).min(bounds.max.x),
+                    centre.y.max(bounds.min.y).min(bounds.max.y),
+                );
+                nearest.distance(centre) <= radius
+            }
+        })
+    }
+
+    /// How much area the two shapes have in common. None when either is a
+    /// triangle.
+    pub fn overlap_area(&self, other: &Placed) -> Option<f64> {
+        Some(match (self.simple()?, other.simple()?) {
+            (Simple::Rectangle(a), Simple::Rectangle(b)) => a.intersection(&b).map_or(0.0, |both| both.area()),
+            (Simple::Circle { centre: a, radius: ra }, Simple::Circle { centre: b, radius: rb }) => {
+                lens_area(a.distance(b), ra, rb)
+            }
+            (Simple::Rectangle(bounds), Simple::Circle { centre, radius })
+            | (Simple::Circle { centre, radius }, Simple::Rectangle(bounds)) => {
+                circle_in_rectangle(centre, radius, &bounds)
+            }
+        })
+    }
+
+    fn simple(&self) -> Option<Simple> {
+        match self.shape {
+            AnyShape::Rectangle(_) | AnyShape::Square(_) => Some(Simple::Rectangle(self.bounds())),
+            AnyShape::Circle(ref c) => Some(Simple::Circle {
+                centre: self.at,
+                radius: c.radius,
+            }),
+            AnyShape::Triangle(_) => None,
+        }
+    }
+}
+
+/// The area two circles `distance` apart have in common.
+fn lens_area(distance: f64, a: f64, b: f64) -> f64 {
+    if distance >= a + b {
+        return 0.0;
+    }
+    if distance <= (a - b).abs() {
+        // one circle is inside the other.
+        let smaller = a.min(b);
+        return PI * smaller * smaller;
+    }
+    // each circle contributes the segment cut off by the line through the
+    // two points where the circles cross.
+    let segment = |r: f64, other: f64| {
+        let cos = (distance * distance + r * r - other * other) / (2.0 * distance * r);
+        let angle = cos.clamp(-1.0, 1.0).acos();
+        r * r * angle - r * r * angle.sin() * angle.cos()
+    };
+    segment(a, b) + segment(b, a)
+}
+
+/// The area of the circle inside the rectangle. corner(x, y) is the area of
+/// the circle between its centre and (x, y), signed by which quarter (x, y)
+/// is in, so the rectangle's four corners add up to the area inside it.
+fn circle_in_rectangle(centre: Point, radius: f64, bounds: &Bounds) -> f64 {
+    if radius <= 0.0 {
+        return 0.0;
+    }
+    let corner = |x: f64, y: f64| {
+        let (x, y) = (x - centre.x, y - centre.y);
+        x.signum() * y.signum() * quarter(x.abs().min(radius), y.abs().min(radius), radius)
+    };
+    let (min, max) = (bounds.min, bounds.max);
+    let area = corner(max.x, max.y) - corner(min.x, max.y) - corner(max.x, min.y) + corner(min.x, min.y);
+    area.max(0.0)
+}
+
+/// The area of a circle centred on the origin inside the box from (0, 0) to
+/// (x, y), for x and y from 0 to the radius.
+fn quarter(x: f64, y: f64, radius: f64) -> f64 {
+    // area under the circle from 0 to t.
+    let under = |t: f64| {
+        (t * (radius * radius - t * t).max(0.0).sqrt() + radius * radius * (t / radius).asin()) / 2.0
+    };
+    // left of `cross` the box is lower than the circle, right of it the
+    // circle is lower.
+    let cross = (radius * radius - y * y).max(0.0).sqrt().min(x);
+    y * cross + under(x) - under(cross)
+}
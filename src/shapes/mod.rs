@@ -0,0 +1,318 @@
+// Shapes that the rest of the crate (and anyone using it) can import. The
+// notes define Shape three different ways while teaching: as a trait
+// returning u32 in traits::traits, as a generic trait in
+// generics::generic_types and as an enum in intro_1::enums_and_options.
+// This is the one to actually use.
+//
+// Both ways of getting polymorphism from the notes are here: the Shape
+// trait, for trait objects (Box<dyn Shape>), and the AnyShape enum, which
+// matches on the kind of shape instead. They give the same answers.
+
+use std::f64::consts::PI;
+
+use area::{self, AreaError};
+
+pub mod ascii;
+pub mod geometry;
+pub mod polygon;
+pub mod scene;
+pub mod svg;
+pub mod text;
+pub mod transform;
+
+/// A position on the plane.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Point {
+    pub fn new(x: f64, y: f64) -> Point {
+        Point { x, y }
+    }
+
+    pub fn distance(&self, other: Point) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
+
+pub trait Shape {
+    /// What kind of shape this is, e.g. "circle".
+    fn kind(&self) -> &'static str;
+    fn area(&self) -> f64;
+    fn perimeter(&self) -> f64;
+
+    /// The area, or an error when it is not a real area: infinite because
+    /// the dimensions are too big for f64, NaN, or negative.
+    fn checked_area(&self) -> Result<f64, AreaError> {
+        area::finite(self.area())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rectangle {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rectangle {
+    pub fn new(width: f64, height: f64) -> Rectangle {
+        Rectangle { width, height }
+    }
+}
+
+impl Shape for Rectangle {
+    fn kind(&self) -> &'static str {
+        "rectangle"
+    }
+
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.width + self.height)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Square {
+    pub side: f64,
+}
+
+impl Square {
+    pub fn new(side: f64) -> Square {
+        Square { side }
+    }
+
+    /// The same square as a Rectangle.
+    pub fn to_rectangle(&self) -> Rectangle {
+        Rectangle::new(self.side, self.side)
+    }
+}
+
+impl Shape for Square {
+    fn kind(&self) -> &'static str {
+        "square"
+    }
+
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+
+    fn perimeter(&self) -> f64 {
+        4.0 * self.side
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    pub radius: f64,
+}
+
+impl Circle {
+    pub fn new(radius: f64) -> Circle {
+        Circle { radius }
+    }
+}
+
+impl Shape for Circle {
+    fn kind(&self) -> &'static str {
+        "circle"
+    }
+
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * PI * self.radius
+    }
+}
+
+/// A triangle given by its corners.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle {
+    pub a: Point,
+    pub b: Point,
+    pub c: Point,
+}
+
+impl Triangle {
+    pub fn new(a: Point, b: Point, c: Point) -> Triangle {
+        Triangle { a, b, c }
+    }
+
+    /// A triangle with sides of these lengths (ab, bc and ca), with a at the
+    /// origin and b along the x axis. None when the sides cannot make a
+    /// triangle, i.e. one is not shorter than the other two together.
+    pub fn from_sides(ab: f64, bc: f64, ca: f64) -> Option<Triangle> {
+        if !(ab > 0.0 && bc > 0.0 && ca > 0.0) || ab >= bc + ca || bc >= ab + ca || ca >= ab + bc {
+            return None;
+        }
+        // c is ca away from a and bc away from b.
+        let x = (ab * ab + ca * ca - bc * bc) / (2.0 * ab);
+        let y = (ca * ca - x * x).max(0.0).sqrt();
+        Some(Triangle::new(Point::new(0.0, 0.0), Point::new(ab, 0.0), Point::new(x, y)))
+    }
+
+    /// Lengths of ab, bc and ca.
+    pub fn sides(&self) -> [f64; 3] {
+        [self.a.distance(self.b), self.b.distance(self.c), self.c.distance(self.a)]
+    }
+}
+
+impl Shape for Triangle {
+    fn kind(&self) -> &'static str {
+        "triangle"
+    }
+
+    fn area(&self) -> f64 {
+        let (a, b, c) = (self.a, self.b, self.c);
+        ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)).abs() / 2.0
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.sides().iter().sum()
+    }
+}
+
+/// Every kind of shape in one type, for when the set of shapes is known up
+/// front: no boxing, and a match instead of a virtual call.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnyShape {
+    Rectangle(Rectangle),
+    Square(Square),
+    Circle(Circle),
+    Triangle(Triangle),
+}
+
+impl AnyShape {
+    /// The shape inside, as a trait object.
+    pub fn as_shape(&self) -> &dyn Shape {
+        match *self {
+            AnyShape::Rectangle(ref shape) => shape,
+            AnyShape::Square(ref shape) => shape,
+            AnyShape::Circle(ref shape) => shape,
+            AnyShape::Triangle(ref shape) => shape,
+        }
+    }
+
+    /// The smallest and largest corners of the space the shape covers,
+    /// relative to its origin (see Placed).
+    pub fn extent(&self) -> (Point, Point) {
+        match *self {
+            AnyShape::Rectangle(ref r) => (Point::new(0.0, 0.0), Point::new(r.width, r.height)),
+            AnyShape::Square(ref s) => (Point::new(0.0, 0.0), Point::new(s.side, s.side)),
+            AnyShape::Circle(ref c) => (Point::new(-c.radius, -c.radius), Point::new(c.radius, c.radius)),
+            AnyShape::Triangle(ref t) => extent_of(&[t.a, t.b, t.c]),
+        }
+    }
+
+    /// The shape inside, boxed as a trait object.
+    pub fn boxed(self) -> Box<dyn Shape> {
+        match self {
+            AnyShape::Rectangle(shape) => Box::new(shape),
+            AnyShape::Square(shape) => Box::new(shape),
+            AnyShape::Circle(shape) => Box::new(shape),
+            AnyShape::Triangle(shape) => Box::new(shape),
+        }
+    }
+}
+
+impl Shape for AnyShape {
+    fn kind(&self) -> &'static str {
+        match *self {
+            AnyShape::Rectangle(ref shape) => shape.kind(),
+            AnyShape::Square(ref shape) => shape.kind(),
+            AnyShape::Circle(ref shape) => shape.kind(),
+            AnyShape::Triangle(ref shape) => shape.kind(),
+        }
+    }
+
+    fn area(&self) -> f64 {
+        match *self {
+            AnyShape::Rectangle(ref shape) => shape.area(),
+            AnyShape::Square(ref shape) => shape.area(),
+            AnyShape::Circle(ref shape) => shape.area(),
+            AnyShape::Triangle(ref shape) => shape.area(),
+        }
+    }
+
+    fn perimeter(&self) -> f64 {
+        match *self {
+            AnyShape::Rectangle(ref shape) => shape.perimeter(),
+            AnyShape::Square(ref shape) => shape.perimeter(),
+            AnyShape::Circle(ref shape) => shape.perimeter(),
+            AnyShape::Triangle(ref shape) => shape.perimeter(),
+        }
+    }
+}
+
+impl From<Rectangle> for AnyShape {
+    fn from(shape: Rectangle) -> AnyShape {
+        AnyShape::Rectangle(shape)
+    }
+}
+
+impl From<Square> for AnyShape {
+    fn from(shape: Square) -> AnyShape {
+        AnyShape::Square(shape)
+    }
+}
+
+impl From<Circle> for AnyShape {
+    fn from(shape: Circle) -> AnyShape {
+        AnyShape::Circle(shape)
+    }
+}
+
+impl From<Triangle> for AnyShape {
+    fn from(shape: Triangle) -> AnyShape {
+        AnyShape::Triangle(shape)
+    }
+}
+
+/// Smallest and largest corners around some points.
+fn extent_of(points: &[Point]) -> (Point, Point) {
+    let mut min = Point::new(f64::INFINITY, f64::INFINITY);
+    let mut max = Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for point in points {
+        min = Point::new(min.x.min(point.x), min.y.min(point.y));
+        max = Point::new(max.x.max(point.x), max.y.max(point.y));
+    }
+    (min, max)
+}
+
+/// A shape somewhere on the plane. `at` is where the shape's origin goes:
+/// rectangles and squares reach from it to (width, height), circles are
+/// centred on it and a triangle's corners are relative to it. y grows
+/// downwards, like on screen and in SVG.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Placed {
+    pub shape: AnyShape,
+    pub at: Point,
+}
+
+impl Placed {
+    pub fn new<S: Into<AnyShape>>(shape: S, at: Point) -> Placed {
+        Placed {
+            shape: shape.into(),
+            at,
+        }
+    }
+
+    /// `shapes` side by side from left to right, `gap` apart, with their
+    /// tops lined up at y = 0.
+    pub fn in_a_row(shapes: &[AnyShape], gap: f64) -> Vec<Placed> {
+        let mut x = 0.0;
+        let mut placed = Vec::with_capacity(shapes.len());
+        for &shape in shapes {
+            let (min, max) = shape.extent();
+            placed.push(Placed::new(shape, Point::new(x - min.x, -min.y)));
+            x += max.x - min.x + gap;
+        }
+        placed
+    }
+}
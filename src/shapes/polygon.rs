@@ -0,0 +1,263 @@
+// Polygons with any number of corners, given in order around the edge.
+// Polygon::new() checks the corners make a real polygon: at least three of
+// them, not all on one line, and no edges crossing each other (a figure of
+// eight has no sensible area). Everything after that can assume a simple
+// polygon.
+//
+// Areas come from the shoelace formula, which adds up the signed area under
+// each edge. The sign says which way round the corners go: positive for
+// counterclockwise with x to the right and y up. On screen, where y grows
+// downwards, the same polygon looks clockwise.
+
+use std::error;
+use std::fmt;
+
+use shapes::{Point, Shape};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolygonError {
+    /// Fewer than three distinct corners.
+    TooFewPoints(usize),
+    /// A corner with an infinite or NaN coordinate.
+    NotFinite { index: usize },
+    /// The same corner twice in a row.
+    RepeatedPoint { index: usize },
+    /// Every corner is on one line, so there is no area.
+    Collinear,
+    /// Two edges cross or overlap. Edge i goes from corner i to the next.
+    SelfIntersecting { first: usize, second: usize },
+}
+
+impl fmt::Display for PolygonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PolygonError::TooFewPoints(count) => {
+                write!(f, "a polygon needs at least 3 corners, found {}", count)
+            }
+            PolygonError::NotFinite { index } => write!(f, "corner {} is not a finite point", index),
+            PolygonError::RepeatedPoint { index } => {
+                write!(f, "corner {} is the same as the one before it", index)
+            }
+            PolygonError::Collinear => write!(f, "every corner is on one line"),
+            PolygonError::SelfIntersecting { first, second } => {
+                write!(f, "edges {} and {} cross each other", first, second)
+            }
+        }
+    }
+}
+
+impl error::Error for PolygonError {}
+
+/// Which way round a polygon's corners go, with x to the right and y up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Winding {
+    Counterclockwise,
+    Clockwise,
+}
+
+/// A simple polygon: its edges only meet at shared corners.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon {
+    points: Vec<Point>,
+}
+
+impl Polygon {
+    /// A polygon through `points` in order, and back to the first. The
+    /// first point can be repeated at the end to close the polygon.
+    pub fn new(mut points: Vec<Point>) -> Result<Polygon, PolygonError> {
+        if points.len() > 1 && points.first() == points.last() {
+            points.pop();
+        }
+        if let Some(index) = points.iter().position(|p| !(p.x.is_finite() && p.y.is_finite())) {
+            return Err(PolygonError::NotFinite { index });
+        }
+        if points.len() < 3 {
+            return Err(PolygonError::TooFewPoints(points.len()));
+        }
+        let n = points.len();
+        if let Some(index) = (0..n).find(|&i| points[i] == points[(i + n - 1) % n]) {
+            return Err(PolygonError::RepeatedPoint { index });
+        }
+        let polygon = Polygon { points };
+        if polygon.all_collinear() {
+            return Err(PolygonError::Collinear);
+        }
+        if let Some((first, second)) = polygon.crossing() {
+            return Err(PolygonError::SelfIntersecting { first, second });
+        }
+        Ok(polygon)
+    }
+
+    /// The smallest convex polygon around all of `points`, counterclockwise
+    /// and without corners in the middle of an edge.
+    pub fn convex_hull(points: &[Point]) -> Result<Polygon, PolygonError> {
+        if let Some(index) = points.iter().position(|p| !(p.x.is_finite() && p.y.is_finite())) {
+            return Err(PolygonError::NotFinite { index });
+        }
+        let mut sorted = points.to_vec();
+        sorted.sort_by(|a, b| (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap());
+        sorted.dedup();
+        if sorted.len() < 3 {
+            return Err(PolygonError::TooFewPoints(sorted.len()));
+        }
+
+        // Andrew's monotone chain: the lower half from left to right, then
+        // the upper half back, keeping only left turns.
+        let mut hull: Vec<Point> = Vec::with_capacity(sorted.len() + 1);
+        for pass in 0..2 {
+            let start = hull.len();
+            for &point in &sorted {
+                while hull.len() >= start + 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], point) <= 0.0 {
+                    hull.pop();
+                }
+                hull.push(point);
+            }
+            // the last point is the first point of the other half.
+            hull.pop();
+            if pass == 0 {
+                sorted.reverse();
+            }
+        }
+        if hull.len() < 3 {
+            return Err(PolygonError::Collinear);
+        }
+        Ok(Polygon { points: hull })
+    }
+
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    /// The area, positive when the corners go counterclockwise.
+    pub fn signed_area(&self) -> f64 {
+        self.edges().map(|(a, b)| a.x * b.y - b.x * a.y).sum::<f64>() / 2.0
+    }
+
+    pub fn winding(&self) -> Winding {
+        if self.signed_area() >= 0.0 {
+            Winding::Counterclockwise
+        } else {
+            Winding::Clockwise
+        }
+    }
+
+    /// The same polygon with its corners going round the way asked for,
+    /// starting from the same corner.
+    pub fn with_winding(mut self, winding: Winding) -> Polygon {
+        if self.winding() != winding {
+            self.points[1..].reverse();
+        }
+        self
+    }
+
+    /// The centre of mass of the polygon's area.
+    pub fn centroid(&self) -> Point {
+        let (mut x, mut y) = (0.0, 0.0);
+        for (a, b) in self.edges() {
+            let cross = a.x * b.y - b.x * a.y;
+            x += (a.x + b.x) * cross;
+            y += (a.y + b.y) * cross;
+        }
+        let six_areas = 6.0 * self.signed_area();
+        Point::new(x / six_areas, y / six_areas)
+    }
+
+    /// Whether every corner turns the same way. Corners in the middle of a
+    /// straight edge do not count as turning.
+    pub fn is_convex(&self) -> bool {
+        let n = self.points.len();
+        let turns: Vec<f64> = (0..n)
+            .map(|i| cross(self.points[i], self.points[(i + 1) % n], self.points[(i + 2) % n]))
+            .filter(|&turn| turn != 0.0)
+            .collect();
+        turns.iter().all(|&turn| turn > 0.0) || turns.iter().all(|&turn| turn < 0.0)
+    }
+
+    /// The convex hull of the corners. Corners that are nearly on one line
+    /// can make a polygon, but still be rounded to no hull at all.
+    pub fn hull(&self) -> Result<Polygon, PolygonError> {
+        Polygon::convex_hull(&self.points)
+    }
+
+    /// Each edge, as its start and end, ending with the one back to the
+    /// first corner.
+    fn edges<'a>(&'a self) -> impl Iterator<Item = (Point, Point)> + 'a {
+        let n = self.points.len();
+        (0..n).map(move |i| (self.points[i], self.points[(i + 1) % n]))
+    }
+
+    fn all_collinear(&self) -> bool {
+        let (a, b) = (self.points[0], self.points[1]);
+        self.points[2..].iter().all(|&c| cross(a, b, c) == 0.0)
+    }
+
+    /// The first pair of edges that meet anywhere other than the corner
+    /// between neighbours.
+    fn crossing(&self) -> Option<(usize, usize)> {
+        let n = self.points.len();
+        let edges: Vec<(Point, Point)> = self.edges().collect();
+        for first in 0..n {
+            for second in first + 1..n {
+                let (a, b) = edges[first];
+                let (c, d) = edges[second];
+                let neighbours = second == first + 1 || (first == 0 && second == n - 1);
+                let meet = if neighbours {
+                    // neighbours share a corner, and only overlap when one
+                    // doubles back along the other.
+                    let (corner, before, after) = if second == first + 1 { (b, a, d) } else { (a, b, c) };
+                    cross(before, corner, after) == 0.0 && dot(before, corner, after) > 0.0
+                } else {
+                    segments_meet(a, b, c, d)
+                };
+                if meet {
+                    return Some((first, second));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Shape for Polygon {
+    fn kind(&self) -> &'static str {
+        "polygon"
+    }
+
+    fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.edges().map(|(a, b)| a.distance(b)).sum()
+    }
+}
+
+/// Positive when a, b, c turn left (counterclockwise), negative when they
+/// turn right and 0 when they are on one line.
+fn cross(a: Point, b: Point, c: Point) -> f64 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+/// Positive when the lines from b to a and from b to c point the same way.
+fn dot(a: Point, b: Point, c: Point) -> f64 {
+    (a.x - b.x) * (c.x - b.x) + (a.y - b.y) * (c.y - b.y)
+}
+
+/// Whether the segments ab and cd have any point in common.
+fn segments_meet(a: Point, b: Point, c: Point, d: Point) -> bool {
+    let (abc, abd) = (cross(a, b, c), cross(a, b, d));
+    let (cda, cdb) = (cross(c, d, a), cross(c, d, b));
+    if ((abc > 0.0 && abd < 0.0) || (abc < 0.0 && abd > 0.0)) && ((cda > 0.0 && cdb < 0.0) || (cda < 0.0 && cdb > 0.0)) {
+        return true;
+    }
+    // otherwise they only meet where an end is on the other segment.
+    (abc == 0.0 && on_segment(a, b, c))
+        || (abd == 0.0 && on_segment(a, b, d))
+        || (cda == 0.0 && on_segment(c, d, a))
+        || (cdb == 0.0 && on_segment(c, d, b))
+}
+
+/// Whether p, which is on the line through a and b, is between them.
+fn on_segment(a: Point, b: Point, p: Point) -> bool {
+    p.x >= a.x.min(b.x) && p.x <= a.x.max(b.x) && p.y >= a.y.min(b.y) && p.y <= a.y.max(b.y)
+}
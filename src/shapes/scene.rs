@@ -0,0 +1,220 @@
+// Collections of shapes, in the two ways the notes get polymorphism:
+//
+// - Scene holds Box<dyn Shape>, so it takes any shape, including ones
+//   defined outside this crate (or a Polygon, which AnyShape has no variant
+//   for), at the cost of a heap allocation per shape and a virtual call per
+//   method.
+// - EnumScene holds AnyShape values in one Vec with no boxing, and matches
+//   on the kind instead, but only takes the kinds AnyShape lists.
+//
+// Both have the same methods and print the same table, so they can be
+// compared side by side.
+
+use std::fmt;
+
+use shapes::{AnyShape, Shape};
+
+/// The shapes of one kind in a collection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group {
+    pub kind: &'static str,
+    pub count: usize,
+    pub total_area: f64,
+    pub smallest: f64,
+    pub largest: f64,
+}
+
+impl Group {
+    pub fn mean_area(&self) -> f64 {
+        self.total_area / self.count as f64
+    }
+}
+
+/// Shapes of any kind, as trait objects.
+#[derive(Default)]
+pub struct Scene {
+    shapes: Vec<Box<dyn Shape>>,
+}
+
+impl Scene {
+    pub fn new() -> Scene {
+        Scene::default()
+    }
+
+    pub fn with<S: Shape + 'static>(mut self, shape: S) -> Scene {
+        self.shapes.push(Box::new(shape));
+        self
+    }
+
+    pub fn push(&mut self, shape: Box<dyn Shape>) {
+        self.shapes.push(shape);
+    }
+
+    pub fn shapes(&self) -> &[Box<dyn Shape>] {
+        &self.shapes
+    }
+
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    pub fn total_area(&self) -> f64 {
+        self.shapes.iter().map(|shape| shape.area()).sum()
+    }
+
+    /// Smallest area first. Shapes with the same area keep their order.
+    pub fn sort_by_area(&mut self) {
+        self.shapes.sort_by(|a, b| a.area().total_cmp(&b.area()));
+    }
+
+    /// The shapes whose kind() is `kind`, e.g. "circle".
+    pub fn of_kind(&self, kind: &str) -> Vec<&dyn Shape> {
+        self.shapes
+            .iter()
+            .map(|shape| &**shape)
+            .filter(|shape| shape.kind() == kind)
+            .collect()
+    }
+
+    /// One group per kind, in the order the kinds first appear.
+    pub fn groups(&self) -> Vec<Group> {
+        groups(self.shapes.iter().map(|shape| &**shape))
+    }
+}
+
+impl fmt::Display for Scene {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        table(f, self.shapes.iter().map(|shape| &**shape))
+    }
+}
+
+impl From<Vec<Box<dyn Shape>>> for Scene {
+    fn from(shapes: Vec<Box<dyn Shape>>) -> Scene {
+        Scene { shapes }
+    }
+}
+
+impl From<EnumScene> for Scene {
+    fn from(scene: EnumScene) -> Scene {
+        Scene {
+            shapes: scene.shapes.into_iter().map(AnyShape::boxed).collect(),
+        }
+    }
+}
+
+/// Shapes of the kinds AnyShape knows, stored inline.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct EnumScene {
+    shapes: Vec<AnyShape>,
+}
+
+impl EnumScene {
+    pub fn new() -> EnumScene {
+        EnumScene::default()
+    }
+
+    pub fn with<S: Into<AnyShape>>(mut self, shape: S) -> EnumScene {
+        self.shapes.push(shape.into());
+        self
+    }
+
+    pub fn push(&mut self, shape: AnyShape) {
+        self.shapes.push(shape);
+    }
+
+    pub fn shapes(&self) -> &[AnyShape] {
+        &self.shapes
+    }
+
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    pub fn total_area(&self) -> f64 {
+        self.shapes.iter().map(Shape::area).sum()
+    }
+
+    /// Smallest area first. Shapes with the same area keep their order.
+    pub fn sort_by_area(&mut self) {
+        self.shapes.sort_by(|a, b| a.area().total_cmp(&b.area()));
+    }
+
+    /// The shapes whose kind() is `kind`, e.g. "circle".
+    pub fn of_kind(&self, kind: &str) -> Vec<&AnyShape> {
+        self.shapes.iter().filter(|shape| shape.kind() == kind).collect()
+    }
+
+    /// One group per kind, in the order the kinds first appear.
+    pub fn groups(&self) -> Vec<Group> {
+        groups(self.shapes.iter().map(|shape| shape as &dyn Shape))
+    }
+}
+
+impl fmt::Display for EnumScene {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        table(f, self.shapes.iter().map(|shape| shape as &dyn Shape))
+    }
+}
+
+impl From<Vec<AnyShape>> for EnumScene {
+    fn from(shapes: Vec<AnyShape>) -> EnumScene {
+        EnumScene { shapes }
+    }
+}
+
+fn groups<'a, I: Iterator<Item = &'a dyn Shape>>(shapes: I) -> Vec<Group> {
+    let mut groups: Vec<Group> = Vec::new();
+    for shape in shapes {
+        let area = shape.area();
+        match groups.iter_mut().find(|group| group.kind == shape.kind()) {
+            Some(group) => {
+                group.count += 1;
+                group.total_area += area;
+                group.smallest = group.smallest.min(area);
+                group.largest = group.largest.max(area);
+            }
+            None => groups.push(Group {
+                kind: shape.kind(),
+                count: 1,
+                total_area: area,
+                smallest: area,
+                largest: area,
+            }),
+        }
+    }
+    groups
+}
+
+/// Every shape with its area and perimeter, then a line per kind and the
+/// total.
+fn table<'a, I: Iterator<Item = &'a dyn Shape> + Clone>(f: &mut fmt::Formatter, shapes: I) -> fmt::Result {
+    writeln!(f, "{:<10} {:>12} {:>12}", "shape", "area", "perimeter")?;
+    let mut count = 0;
+    let mut total = 0.0;
+    for shape in shapes.clone() {
+        writeln!(f, "{:<10} {:>12.2} {:>12.2}", shape.kind(), shape.area(), shape.perimeter())?;
+        count += 1;
+        total += shape.area();
+    }
+    for group in groups(shapes) {
+        writeln!(
+            f,
+            "{} {}(s): total {:.2}, mean {:.2}, smallest {:.2}, largest {:.2}",
+            group.count,
+            group.kind,
+            group.total_area,
+            group.mean_area(),
+            group.smallest,
+            group.largest
+        )?;
+    }
+    writeln!(f, "{} shape(s), total area {:.2}", count, total)
+}
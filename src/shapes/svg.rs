@@ -0,0 +1,193 @@
+// Draws placed shapes as an SVG document, so the notes can show pictures
+// next to the areas they print. The viewBox is worked out from the shapes,
+// and numbers are written with at most 3 decimals in a fixed order, so the
+// same shapes always give byte-for-byte the same document.
+//
+//     let svg = Svg::new()
+//         .add(Placed::new(Rectangle::new(30.0, 20.0), Point::new(0.0, 0.0)), Style::default())
+//         .render();
+
+use shapes::{AnyShape, Placed, Point, Shape};
+
+/// How a shape is painted. Colours are anything SVG understands, e.g.
+/// "#3182bd", "red" or "none".
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    pub fill: String,
+    pub stroke: String,
+    pub stroke_width: f64,
+}
+
+impl Style {
+    pub fn new(fill: &str, stroke: &str, stroke_width: f64) -> Style {
+        Style {
+            fill: fill.to_string(),
+            stroke: stroke.to_string(),
+            stroke_width,
+        }
+    }
+
+    /// Only the outline.
+    pub fn outline(stroke: &str) -> Style {
+        Style::new("none", stroke, 1.0)
+    }
+}
+
+impl Default for Style {
+    fn default() -> Style {
+        Style::new("#9ecae1", "#3182bd", 1.0)
+    }
+}
+
+/// A document being put together.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svg {
+    items: Vec<(Placed, Style)>,
+    margin: f64,
+}
+
+impl Default for Svg {
+    fn default() -> Svg {
+        Svg {
+            items: Vec::new(),
+            margin: 10.0,
+        }
+    }
+}
+
+impl Svg {
+    pub fn new() -> Svg {
+        Svg::default()
+    }
+
+    /// Space around the shapes, inside the viewBox. 10 by default.
+    pub fn margin(mut self, margin: f64) -> Svg {
+        self.margin = margin;
+        self
+    }
+
+    /// Shapes are drawn in the order they are added, later ones on top.
+    pub fn add(mut self, placed: Placed, style: Style) -> Svg {
+        self.items.push((placed, style));
+        self
+    }
+
+    /// The viewBox as (min x, min y, width, height): every shape, its
+    /// stroke and the margin.
+    pub fn view_box(&self) -> (f64, f64, f64, f64) {
+        let mut min = Point::new(0.0, 0.0);
+        let mut max = Point::new(0.0, 0.0);
+        for (index, (placed, style)) in self.items.iter().enumerate() {
+            let (low, high) = placed.shape.extent();
+            let half_stroke = style.stroke_width / 2.0;
+            let low = Point::new(placed.at.x + low.x - half_stroke, placed.at.y + low.y - half_stroke);
+            let high = Point::new(placed.at.x + high.x + half_stroke, placed.at.y + high.y + half_stroke);
+            if index == 0 {
+                min = low;
+                max = high;
+            } else {
+                min = Point::new(min.x.min(low.x), min.y.min(low.y));
+                max = Point::new(max.x.max(high.x), max.y.max(high.y));
+            }
+        }
+        (
+            min.x - self.margin,
+            min.y - self.margin,
+            max.x - min.x + 2.0 * self.margin,
+            max.y - min.y + 2.0 * self.margin,
+        )
+    }
+
+    pub fn render(&self) -> String {
+        let (x, y, width, height) = self.view_box();
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\" width=\"{}\" height=\"{}\">\n",
+            number(x),
+            number(y),
+            number(width),
+            number(height),
+            number(width),
+            number(height)
+        );
+        for (placed, style) in &self.items {
+            svg.push_str(&element(placed, style));
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// `shapes` in a row, each in the default style.
+pub fn render(shapes: &[AnyShape]) -> String {
+    Placed::in_a_row(shapes, 10.0)
+        .into_iter()
+        .fold(Svg::new(), |svg, placed| svg.add(placed, Style::default()))
+        .render()
+}
+
+fn element(placed: &Placed, style: &Style) -> String {
+    let at = placed.at;
+    let shape = match placed.shape {
+        AnyShape::Rectangle(ref r) => rect(at, r.width, r.height),
+        AnyShape::Square(ref s) => rect(at, s.side, s.side),
+        AnyShape::Circle(ref c) => format!(
+            "<circle cx=\"{}\" cy=\"{}\" r=\"{}\"",
+            number(at.x),
+            number(at.y),
+            number(c.radius)
+        ),
+        AnyShape::Triangle(ref t) => format!(
+            "<polygon points=\"{}\"",
+            [t.a, t.b, t.c]
+                .iter()
+                .map(|corner| format!("{},{}", number(at.x + corner.x), number(at.y + corner.y)))
+                .collect::<Vec<_>>()
+                .join(" ")
+        ),
+    };
+    // the title shows up as a tooltip, and says what the notes print.
+    format!(
+        "  {} fill=\"{}\" stroke=\"{}\" stroke-width=\"{}\"><title>{}, area {}</title></{}>\n",
+        shape,
+        escape(&style.fill),
+        escape(&style.stroke),
+        number(style.stroke_width),
+        placed.shape.kind(),
+        number(placed.shape.area()),
+        tag(&placed.shape)
+    )
+}
+
+fn rect(at: Point, width: f64, height: f64) -> String {
+    format!(
+        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"",
+        number(at.x),
+        number(at.y),
+        number(width),
+        number(height)
+    )
+}
+
+fn tag(shape: &AnyShape) -> &'static str {
+    match *shape {
+        AnyShape::Rectangle(_) | AnyShape::Square(_) => "rect",
+        AnyShape::Circle(_) => "circle",
+        AnyShape::Triangle(_) => "polygon",
+    }
+}
+
+/// At most 3 decimals and no trailing zeros, so 10 is "10" and 1/3 is
+/// "0.333".
+fn number(value: f64) -> String {
+    let text = format!("{:.3}", value);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" {
+        String::from("0")
+    } else {
+        text.to_string()
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('"', "&quot;").replace('<', "&lt;")
+}
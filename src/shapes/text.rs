@@ -0,0 +1,194 @@
+// A small text format for lists of shapes, one per line:
+//
+//     # the shapes from the traits notes
+//     rect 30 20
+//     circle 100.1
+//     square 10
+//     triangle 0 0 4 0 0 3
+//
+// `rectangle` can be spelled out, triangles are given by their corners, and
+// everything after a # is a comment. print() writes shapes back out in the
+// same format, so parse(print(shapes)) gives the same shapes again.
+
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+use shapes::{AnyShape, Circle, Point, Rectangle, Square, Triangle};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    UnknownShape(String),
+    /// The line ended before all of a shape's numbers were given.
+    MissingNumber { shape: &'static str, expected: usize },
+    NotANumber(String),
+    /// Infinity or NaN.
+    NotFinite(String),
+    /// A size (width, height, side or radius) below zero.
+    Negative(String),
+    /// Something after the last number.
+    Unexpected(String),
+    /// A blank line where a shape was expected.
+    Empty,
+}
+
+/// Where in the text parsing failed and why. Lines and columns count from
+/// 1, and columns count characters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match self.kind {
+            ErrorKind::UnknownShape(ref word) => write!(
+                f,
+                "unknown shape `{}` (expected rect, square, circle or triangle)",
+                word
+            ),
+            ErrorKind::MissingNumber { shape, expected } => {
+                write!(f, "{} needs {} number(s)", shape, expected)
+            }
+            ErrorKind::NotANumber(ref word) => write!(f, "expected a number, found `{}`", word),
+            ErrorKind::NotFinite(ref word) => write!(f, "`{}` is not a finite number", word),
+            ErrorKind::Negative(ref word) => write!(f, "a size cannot be negative, found `{}`", word),
+            ErrorKind::Unexpected(ref word) => write!(f, "unexpected `{}` after the last number", word),
+            ErrorKind::Empty => write!(f, "expected a shape"),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Every shape in `text`, in order.
+pub fn parse(text: &str) -> Result<Vec<AnyShape>, ParseError> {
+    let mut shapes = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let parsed = parse_line(line).map_err(|(column, kind)| ParseError {
+            line: index + 1,
+            column,
+            kind,
+        })?;
+        if let Some(shape) = parsed {
+            shapes.push(shape);
+        }
+    }
+    Ok(shapes)
+}
+
+/// The shapes in the text format, one per line.
+pub fn print(shapes: &[AnyShape]) -> String {
+    shapes.iter().map(|shape| format!("{}\n", shape)).collect()
+}
+
+/// A word and the column it starts at.
+type Word<'a> = (usize, &'a str);
+
+/// None for blank and comment lines. Errors are the column and what went
+/// wrong, the caller knows the line.
+fn parse_line(line: &str) -> Result<Option<AnyShape>, (usize, ErrorKind)> {
+    let line = match line.find('#') {
+        Some(comment) => &line[..comment],
+        None => line,
+    };
+    let words = words(line);
+    let (column, name) = match words.first() {
+        Some(&word) => word,
+        None => return Ok(None),
+    };
+    let (shape, expected, sizes) = match name {
+        "rect" | "rectangle" => ("rect", 2, true),
+        "square" => ("square", 1, true),
+        "circle" => ("circle", 1, true),
+        "triangle" => ("triangle", 6, false),
+        _ => return Err((column, ErrorKind::UnknownShape(name.to_string()))),
+    };
+
+    let mut numbers = Vec::with_capacity(expected);
+    for &(column, word) in words[1..].iter().take(expected) {
+        numbers.push(number(column, word, sizes)?);
+    }
+    if numbers.len() < expected {
+        // point just past the end of what is there.
+        let end = line.trim_end().chars().count() + 1;
+        return Err((end, ErrorKind::MissingNumber { shape, expected }));
+    }
+    if let Some(&(column, word)) = words.get(expected + 1) {
+        return Err((column, ErrorKind::Unexpected(word.to_string())));
+    }
+
+    let n = &numbers;
+    Ok(Some(match shape {
+        "rect" => Rectangle::new(n[0], n[1]).into(),
+        "square" => Square::new(n[0]).into(),
+        "circle" => Circle::new(n[0]).into(),
+        _ => Triangle::new(Point::new(n[0], n[1]), Point::new(n[2], n[3]), Point::new(n[4], n[5])).into(),
+    }))
+}
+
+fn words(line: &str) -> Vec<Word<'_>> {
+    let mut words = Vec::new();
+    let mut start: Option<(usize, usize)> = None;
+    for (column, (index, c)) in line.char_indices().enumerate() {
+        match (c.is_whitespace(), start) {
+            (false, None) => start = Some((column + 1, index)),
+            (true, Some((column, begin))) => {
+                words.push((column, &line[begin..index]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some((column, begin)) = start {
+        words.push((column, &line[begin..]));
+    }
+    words
+}
+
+fn number(column: usize, word: &str, size: bool) -> Result<f64, (usize, ErrorKind)> {
+    let value: f64 = word
+        .parse()
+        .map_err(|_| (column, ErrorKind::NotANumber(word.to_string())))?;
+    if !value.is_finite() {
+        Err((column, ErrorKind::NotFinite(word.to_string())))
+    } else if size && value < 0.0 {
+        Err((column, ErrorKind::Negative(word.to_string())))
+    } else {
+        Ok(value)
+    }
+}
+
+impl fmt::Display for AnyShape {
+    /// One line of the text format (without the newline).
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // f64's Display prints the shortest text that parses back to the
+        // same number, which is what makes round trips exact.
+        match *self {
+            AnyShape::Rectangle(ref r) => write!(f, "rect {} {}", r.width, r.height),
+            AnyShape::Square(ref s) => write!(f, "square {}", s.side),
+            AnyShape::Circle(ref c) => write!(f, "circle {}", c.radius),
+            AnyShape::Triangle(ref t) => write!(
+                f,
+                "triangle {} {} {} {} {} {}",
+                t.a.x, t.a.y, t.b.x, t.b.y, t.c.x, t.c.y
+            ),
+        }
+    }
+}
+
+impl FromStr for AnyShape {
+    type Err = ParseError;
+
+    /// A single line of the text format.
+    fn from_str(line: &str) -> Result<AnyShape, ParseError> {
+        let error = |(column, kind)| ParseError { line: 1, column, kind };
+        match parse_line(line).map_err(error)? {
+            Some(shape) => Ok(shape),
+            None => Err(error((1, ErrorKind::Empty))),
+        }
+    }
+}
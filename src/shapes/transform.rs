@@ -0,0 +1,334 @@
+// Arithmetic on points and vectors, and affine transforms (moving,
+// scaling, rotating and any mix of them) of every kind of shape.
+//
+// A Point is a position and a Vector2 is a step between positions, so
+// point - point is a vector, point + vector is a point, and vectors can be
+// added, scaled and negated. These are the same std::ops traits as the
+// A + B example in _traits.
+//
+// A transform keeps straight lines straight, but not every shape stays the
+// same kind of shape: a rectangle turned by 30 degrees is no longer a
+// Rectangle (which has its sides along the axes), so it becomes a Polygon,
+// and a circle stretched in one direction becomes a polygon following the
+// ellipse. Triangles always stay triangles.
+
+use std::f64::consts::PI;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+use shapes::polygon::{Polygon, PolygonError};
+use shapes::{AnyShape, Circle, Placed, Point, Rectangle, Shape, Square, Triangle};
+
+/// How many corners the polygon standing in for a stretched circle has.
+pub const ELLIPSE_CORNERS: usize = 64;
+
+/// A step across the plane.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Vector2 {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Vector2 {
+    pub fn new(x: f64, y: f64) -> Vector2 {
+        Vector2 { x, y }
+    }
+
+    pub fn length(&self) -> f64 {
+        self.x.hypot(self.y)
+    }
+
+    pub fn dot(&self, other: Vector2) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+}
+
+impl Add for Vector2 {
+    type Output = Vector2;
+
+    fn add(self, other: Vector2) -> Vector2 {
+        Vector2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector2 {
+    type Output = Vector2;
+
+    fn sub(self, other: Vector2) -> Vector2 {
+        Vector2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<f64> for Vector2 {
+    type Output = Vector2;
+
+    fn mul(self, scale: f64) -> Vector2 {
+        Vector2::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl Mul<Vector2> for f64 {
+    type Output = Vector2;
+
+    fn mul(self, vector: Vector2) -> Vector2 {
+        vector * self
+    }
+}
+
+impl Neg for Vector2 {
+    type Output = Vector2;
+
+    fn neg(self) -> Vector2 {
+        Vector2::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Vector2 {
+    fn add_assign(&mut self, other: Vector2) {
+        *self = *self + other;
+    }
+}
+
+impl Add<Vector2> for Point {
+    type Output = Point;
+
+    fn add(self, step: Vector2) -> Point {
+        Point::new(self.x + step.x, self.y + step.y)
+    }
+}
+
+impl Sub<Vector2> for Point {
+    type Output = Point;
+
+    fn sub(self, step: Vector2) -> Point {
+        self + -step
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector2;
+
+    /// The step from `other` to this point.
+    fn sub(self, other: Point) -> Vector2 {
+        Vector2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl AddAssign<Vector2> for Point {
+    fn add_assign(&mut self, step: Vector2) {
+        *self = *self + step;
+    }
+}
+
+/// An affine transform, as the matrix
+///
+/// ```text
+/// | a c e |
+/// | b d f |
+/// ```
+///
+/// so (x, y) goes to (a x + c y + e, b x + d y + f), the same order as
+/// SVG's matrix().
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub d: f64,
+    pub e: f64,
+    pub f: f64,
+}
+
+impl Default for Transform {
+    fn default() -> Transform {
+        Transform::identity()
+    }
+}
+
+impl Transform {
+    /// Leaves everything where it is.
+    pub fn identity() -> Transform {
+        Transform::scale(1.0, 1.0)
+    }
+
+    pub fn translate(step: Vector2) -> Transform {
+        Transform {
+            e: step.x,
+            f: step.y,
+            ..Transform::identity()
+        }
+    }
+
+    /// Scales about the origin. A negative scale mirrors.
+    pub fn scale(x: f64, y: f64) -> Transform {
+        Transform {
+            a: x,
+            b: 0.0,
+            c: 0.0,
+            d: y,
+            e: 0.0,
+            f: 0.0,
+        }
+    }
+
+    /// Turns about the origin by `radians`, from the x axis towards the y
+    /// axis: clockwise on screen, where y grows downwards.
+    pub fn rotate(radians: f64) -> Transform {
+        let (sin, cos) = radians.sin_cos();
+        Transform {
+            a: cos,
+            b: sin,
+            c: -sin,
+            d: cos,
+            ..Transform::identity()
+        }
+    }
+
+    /// This transform followed by `next`.
+    pub fn then(&self, next: Transform) -> Transform {
+        Transform {
+            a: next.a * self.a + next.c * self.b,
+            b: next.b * self.a + next.d * self.b,
+            c: next.a * self.c + next.c * self.d,
+            d: next.b * self.c + next.d * self.d,
+            e: next.a * self.e + next.c * self.f + next.e,
+            f: next.b * self.e + next.d * self.f + next.f,
+        }
+    }
+
+    pub fn apply(&self, point: Point) -> Point {
+        Point::new(
+            self.a * point.x + self.c * point.y + self.e,
+            self.b * point.x + self.d * point.y + self.f,
+        )
+    }
+
+    /// How much areas are scaled by, negative when the transform mirrors.
+    /// 0 when it squashes everything onto a line or a point.
+    pub fn determinant(&self) -> f64 {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// A step is only turned and scaled; moving does not change it.
+    pub fn apply_vector(&self, step: Vector2) -> Vector2 {
+        Vector2::new(self.a * step.x + self.c * step.y, self.b * step.x + self.d * step.y)
+    }
+
+    /// The shape after the transform. Every kind of shape gives
+    /// PolygonError::Collinear when the transform squashes it flat (scaling
+    /// by 0), since what is left has no area.
+    pub fn apply_to(&self, placed: &Placed) -> Result<Transformed, PolygonError> {
+        if self.determinant() == 0.0 {
+            return Err(PolygonError::Collinear);
+        }
+        let at = self.apply(placed.at);
+        // where the shape's x and y axes end up.
+        let (x, y) = (self.apply_vector(Vector2::new(1.0, 0.0)), self.apply_vector(Vector2::new(0.0, 1.0)));
+        let axis_aligned = (near(x.y, 0.0) && near(y.x, 0.0)) || (near(x.x, 0.0) && near(y.y, 0.0));
+        let corners = |width: f64, height: f64| {
+            vec![
+                placed.at,
+                placed.at + Vector2::new(width, 0.0),
+                placed.at + Vector2::new(width, height),
+                placed.at + Vector2::new(0.0, height),
+            ]
+        };
+
+        Ok(match placed.shape {
+            AnyShape::Rectangle(ref r) if axis_aligned => {
+                Transformed::Shape(self.aligned(&corners(r.width, r.height), |w, h| Rectangle::new(w, h).into()))
+            }
+            AnyShape::Square(ref s) if axis_aligned => Transformed::Shape(self.aligned(&corners(s.side, s.side), |w, h| {
+                // stretched in one direction, it is a rectangle.
+                if near(w, h) {
+                    Square::new(w).into()
+                } else {
+                    Rectangle::new(w, h).into()
+                }
+            })),
+            AnyShape::Rectangle(ref r) => self.polygon(corners(r.width, r.height))?,
+            AnyShape::Square(ref s) => self.polygon(corners(s.side, s.side))?,
+            // a circle stays a circle when the transform turns and scales
+            // both axes the same way.
+            AnyShape::Circle(ref c) if near(x.length(), y.length()) && near(x.dot(y), 0.0) => {
+                Transformed::Shape(Placed::new(Circle::new(c.radius * x.length()), at))
+            }
+            AnyShape::Circle(ref c) => {
+                let around = (0..ELLIPSE_CORNERS)
+                    .map(|i| {
+                        let (sin, cos) = (2.0 * PI * i as f64 / ELLIPSE_CORNERS as f64).sin_cos();
+                        placed.at + Vector2::new(cos, sin) * c.radius
+                    })
+                    .collect();
+                self.polygon(around)?
+            }
+            AnyShape::Triangle(ref t) => {
+                // the corners are relative to `at`, so they are only turned
+                // and scaled.
+                let corner = |p: Point| {
+                    let step = self.apply_vector(Vector2::new(p.x, p.y));
+                    Point::new(step.x, step.y)
+                };
+                Transformed::Shape(Placed::new(Triangle::new(corner(t.a), corner(t.b), corner(t.c)), at))
+            }
+        })
+    }
+
+    pub fn apply_to_polygon(&self, polygon: &Polygon) -> Result<Polygon, PolygonError> {
+        Polygon::new(polygon.points().iter().map(|&p| self.apply(p)).collect())
+    }
+
+    /// A rectangle or square whose corners are still lined up with the axes
+    /// after the transform, at its new top left corner.
+    fn aligned<F: Fn(f64, f64) -> AnyShape>(&self, corners: &[Point], shape: F) -> Placed {
+        let moved: Vec<Point> = corners.iter().map(|&p| self.apply(p)).collect();
+        let min = Point::new(
+            moved.iter().map(|p| p.x).fold(f64::INFINITY, f64::min),
+            moved.iter().map(|p| p.y).fold(f64::INFINITY, f64::min),
+        );
+        let max = Point::new(
+            moved.iter().map(|p| p.x).fold(f64::NEG_INFINITY, f64::max),
+            moved.iter().map(|p| p.y).fold(f64::NEG_INFINITY, f64::max),
+        );
+        Placed::new(shape(max.x - min.x, max.y - min.y), min)
+    }
+
+    fn polygon(&self, corners: Vec<Point>) -> Result<Transformed, PolygonError> {
+        Polygon::new(corners.into_iter().map(|p| self.apply(p)).collect()).map(Transformed::Polygon)
+    }
+}
+
+/// A shape after a transform: still one AnyShape knows, or a polygon.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Transformed {
+    Shape(Placed),
+    /// With its corners where they are on the plane.
+    Polygon(Polygon),
+}
+
+impl Shape for Transformed {
+    fn kind(&self) -> &'static str {
+        match *self {
+            Transformed::Shape(ref placed) => placed.shape.kind(),
+            Transformed::Polygon(ref polygon) => polygon.kind(),
+        }
+    }
+
+    fn area(&self) -> f64 {
+        match *self {
+            Transformed::Shape(ref placed) => placed.shape.area(),
+            Transformed::Polygon(ref polygon) => polygon.area(),
+        }
+    }
+
+    fn perimeter(&self) -> f64 {
+        match *self {
+            Transformed::Shape(ref placed) => placed.shape.perimeter(),
+            Transformed::Polygon(ref polygon) => polygon.perimeter(),
+        }
+    }
+}
+
+/// Equal apart from rounding, like cos(90°) coming out as 6e-17.
+fn near(a: f64, b: f64) -> bool {
+    (a - b).abs() <= 1e-9 * (1.0 + a.abs().max(b.abs()))
+}
@@ -0,0 +1,197 @@
+// Golden output for the lessons. Each lesson's output is checked in under
+// snapshots/<module>/<name>.txt, and running the lessons again tells us
+// whether editing the notes changed what they print.
+//
+// Check:  cargo test (or `rust_playground snapshot`)
+// Update: UPDATE_SNAPSHOTS=1 cargo test (or `rust_playground snapshot --update`)
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use lesson::{Lesson, Registry};
+
+/// Lines of unchanged output shown around each change in a diff.
+const CONTEXT: usize = 2;
+
+/// Tag for lessons whose output depends on the compiler or the target (like
+/// type sizes), so there is nothing stable to snapshot.
+pub const MACHINE_SPECIFIC: &str = "machine-specific";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mode {
+    /// Compare against the checked in files.
+    Check,
+    /// Rewrite the checked in files with the current output.
+    Update,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Matched,
+    /// The output differs from the snapshot; holds a readable diff.
+    Changed(String),
+    /// There is no snapshot for this lesson yet.
+    Missing,
+    /// The snapshot was (re)written in update mode.
+    Updated,
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub lesson: String,
+    pub path: PathBuf,
+    pub status: Status,
+}
+
+impl Report {
+    pub fn failed(&self) -> bool {
+        match self.status {
+            Status::Changed(_) | Status::Missing => true,
+            Status::Matched | Status::Updated => false,
+        }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.status {
+            Status::Matched => write!(f, "{}: ok", self.lesson),
+            Status::Updated => write!(f, "{}: updated {}", self.lesson, self.path.display()),
+            Status::Missing => write!(
+                f,
+                "{}: no snapshot at {} (run with UPDATE_SNAPSHOTS=1 to create it)",
+                self.lesson,
+                self.path.display()
+            ),
+            Status::Changed(ref diff) => write!(
+                f,
+                "{}: output differs from {}\n{}",
+                self.lesson,
+                self.path.display(),
+                diff
+            ),
+        }
+    }
+}
+
+/// Where the snapshot for `lesson` lives inside `dir`.
+pub fn path_for(dir: &Path, lesson: &dyn Lesson) -> PathBuf {
+    dir.join(lesson.module()).join(format!("{}.txt", lesson.name()))
+}
+
+/// Run one lesson and compare (or update) its snapshot.
+pub fn check(dir: &Path, lesson: &dyn Lesson, mode: Mode) -> io::Result<Report> {
+    let path = path_for(dir, lesson);
+    let actual = lesson.capture();
+
+    let status = match mode {
+        Mode::Update => {
+            fs::create_dir_all(path.parent().unwrap_or(dir))?;
+            fs::write(&path, &actual)?;
+            Status::Updated
+        }
+        Mode::Check => match fs::read_to_string(&path) {
+            Ok(ref expected) if *expected == actual => Status::Matched,
+            Ok(expected) => Status::Changed(diff(&expected, &actual)),
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => Status::Missing,
+            Err(err) => return Err(err),
+        },
+    };
+
+    Ok(Report {
+        lesson: lesson.full_name(),
+        path,
+        status,
+    })
+}
+
+/// Check every lesson in the registry, apart from machine specific ones.
+pub fn check_all(dir: &Path, registry: &Registry, mode: Mode) -> io::Result<Vec<Report>> {
+    registry
+        .iter()
+        .filter(|lesson| !lesson.has_tag(MACHINE_SPECIFIC))
+        .map(|lesson| check(dir, lesson, mode))
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Edit {
+    Same,
+    Removed,
+    Added,
+}
+
+/// A line diff of `expected` against `actual`, with `-` for lines that went
+/// missing and `+` for new ones, grouped into hunks with a little context.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // longest common subsequence table, lcs[i][j] is the answer for
+    // old[i..] and new[j..].
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    // walk the table to get the edits, remembering each line's number in
+    // the expected output for the hunk headers.
+    let mut edits = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            edits.push((Edit::Same, i + 1, old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            edits.push((Edit::Removed, i + 1, old[i]));
+            i += 1;
+        } else {
+            edits.push((Edit::Added, i + 1, new[j]));
+            j += 1;
+        }
+    }
+
+    let changed: Vec<usize> = (0..edits.len())
+        .filter(|&k| edits[k].0 != Edit::Same)
+        .collect();
+    if changed.is_empty() {
+        // same lines, so only the trailing newline can differ.
+        return String::from("(only the final newline differs)\n");
+    }
+
+    let mut text = String::new();
+    let mut k = 0;
+    while k < changed.len() {
+        let start = changed[k].saturating_sub(CONTEXT);
+        let mut end = changed[k];
+        // merge changes whose context would overlap into one hunk.
+        while k + 1 < changed.len() && changed[k + 1] <= end + 2 * CONTEXT + 1 {
+            k += 1;
+            end = changed[k];
+        }
+        let end = (end + CONTEXT + 1).min(edits.len());
+
+        text.push_str(&format!("@@ line {} @@\n", edits[start].1));
+        for &(edit, _, line) in &edits[start..end] {
+            let marker = match edit {
+                Edit::Same => ' ',
+                Edit::Removed => '-',
+                Edit::Added => '+',
+            };
+            text.push(marker);
+            text.push_str(line);
+            text.push('\n');
+        }
+        k += 1;
+    }
+    text
+}
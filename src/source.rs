@@ -0,0 +1,123 @@
+// The source code of the notes, embedded at build time with include_str! so
+// the commentary next to the code can be shown while running a lesson.
+
+/// One notes file. `file` is what file!() says inside it, which is also
+/// what outln! records, so printed lines can be matched back to the file.
+#[derive(Debug, Clone, Copy)]
+pub struct Source {
+    pub file: &'static str,
+    pub text: &'static str,
+}
+
+/// A function cut out of a Source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function<'a> {
+    /// Line number (starting at 1) of the `fn` line.
+    pub first_line: u32,
+    /// Lines from the `fn` line through the closing brace.
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Function<'a> {
+    /// The lines paired with their line numbers in the file.
+    pub fn numbered(&self) -> impl Iterator<Item = (u32, &'a str)> + '_ {
+        let first_line = self.first_line;
+        self.lines
+            .iter()
+            .enumerate()
+            .map(move |(i, line)| (first_line + i as u32, *line))
+    }
+
+    pub fn contains_line(&self, line: u32) -> bool {
+        line >= self.first_line && line < self.first_line + self.lines.len() as u32
+    }
+}
+
+impl Source {
+    /// Find the function called `name` and return it with any doc comments
+    /// directly above it.
+    pub fn function(&self, name: &str) -> Option<Function<'static>> {
+        let lines: Vec<&'static str> = self.text.lines().collect();
+        let pattern = format!("fn {}(", name);
+        let start = lines.iter().position(|line| {
+            let line = line.trim_start();
+            line.starts_with(&pattern) || line.starts_with(&format!("pub {}", pattern))
+        })?;
+
+        // doc comments belong to the function, so keep them.
+        let mut first = start;
+        while first > 0 && lines[first - 1].trim_start().starts_with("///") {
+            first -= 1;
+        }
+
+        let end = start + closing_line(&lines[start..])?;
+        Some(Function {
+            first_line: first as u32 + 1,
+            lines: lines[first..=end].to_vec(),
+        })
+    }
+}
+
+/// Index of the line holding the brace that closes the first `{` in
+/// `lines`. Braces inside strings, chars and comments are skipped (the
+/// notes print a lot of "{}").
+fn closing_line(lines: &[&str]) -> Option<usize> {
+    let mut depth = 0;
+    let mut opened = false;
+    let mut in_block_comment = false;
+    let mut in_string = false;
+
+    for (index, line) in lines.iter().enumerate() {
+        let chars: Vec<char> = line.chars().collect();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).cloned();
+            if in_block_comment {
+                if c == '*' && next == Some('/') {
+                    in_block_comment = false;
+                    i += 1;
+                }
+            } else if in_string {
+                if c == '\\' {
+                    i += 1;
+                } else if c == '"' {
+                    in_string = false;
+                }
+            } else {
+                match c {
+                    '/' if next == Some('/') => break,
+                    '/' if next == Some('*') => {
+                        in_block_comment = true;
+                        i += 1;
+                    }
+                    '"' => in_string = true,
+                    // a char literal ('{', '\n') rather than a lifetime ('a).
+                    '\'' if next == Some('\\') => {
+                        // skip the backslash and the escaped character,
+                        // then move on to the closing quote.
+                        i += 2;
+                        while i + 1 < chars.len() && chars[i + 1] != '\'' {
+                            i += 1;
+                        }
+                        i += 1;
+                    }
+                    '\'' if chars.get(i + 2) == Some(&'\'') => i += 2,
+                    '{' => {
+                        depth += 1;
+                        opened = true;
+                    }
+                    '}' => {
+                        depth -= 1;
+                        if opened && depth == 0 {
+                            return Some(index);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            i += 1;
+        }
+    }
+    None
+}
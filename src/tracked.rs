@@ -0,0 +1,181 @@
+// A general version of the DropStruct from the traits lesson: Tracked<T>
+// wraps any value and writes down when it is created, cloned and dropped in
+// a Log, so lessons can show (and tests can check) exactly when ownership
+// ends, instead of every example needing its own Drop impl that prints.
+//
+//     let log = Log::new();
+//     {
+//         let v = log.track("v", vec![1, 2, 3]);
+//         let w = v.clone();
+//     }
+//     assert_eq!(log.dropped(), ["v clone 1", "v"]);
+
+use std::cell::RefCell;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Created(String),
+    Cloned { original: String, clone: String },
+    Dropped(String),
+    /// Something the lesson wanted to mark in between the other events.
+    Note(String),
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Event::Created(ref label) => write!(f, "created {}", label),
+            Event::Cloned {
+                ref original,
+                ref clone,
+            } => write!(f, "cloned {} as {}", original, clone),
+            Event::Dropped(ref label) => write!(f, "dropped {}", label),
+            Event::Note(ref text) => write!(f, "-- {}", text),
+        }
+    }
+}
+
+/// Where tracked values write their events. Cloning a Log gives another
+/// handle to the same events.
+#[derive(Debug, Clone, Default)]
+pub struct Log {
+    events: Rc<RefCell<Vec<Event>>>,
+}
+
+impl Log {
+    pub fn new() -> Log {
+        Log::default()
+    }
+
+    /// Start tracking `value` under `label`.
+    pub fn track<T>(&self, label: &str, value: T) -> Tracked<T> {
+        self.push(Event::Created(label.to_string()));
+        Tracked {
+            label: label.to_string(),
+            value,
+            log: self.clone(),
+        }
+    }
+
+    pub fn note(&self, text: &str) {
+        self.push(Event::Note(text.to_string()));
+    }
+
+    /// Every event so far, oldest first.
+    pub fn events(&self) -> Vec<Event> {
+        self.events.borrow().clone()
+    }
+
+    /// Labels of the values dropped so far, in the order they were dropped.
+    pub fn dropped(&self) -> Vec<String> {
+        self.events
+            .borrow()
+            .iter()
+            .filter_map(|event| match *event {
+                Event::Dropped(ref label) => Some(label.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Labels of the values created or cloned that have not been dropped yet.
+    pub fn alive(&self) -> Vec<String> {
+        let mut alive = Vec::new();
+        for event in self.events.borrow().iter() {
+            match *event {
+                Event::Created(ref label) | Event::Cloned { clone: ref label, .. } => {
+                    alive.push(label.clone())
+                }
+                Event::Dropped(ref label) => alive.retain(|alive| alive != label),
+                Event::Note(_) => {}
+            }
+        }
+        alive
+    }
+
+    fn push(&self, event: Event) {
+        self.events.borrow_mut().push(event);
+    }
+
+    /// Clones are labelled after their original: "v clone 1", "v clone 2".
+    fn clone_label(&self, original: &str) -> String {
+        let clones = self
+            .events
+            .borrow()
+            .iter()
+            .filter(|event| match **event {
+                Event::Cloned { original: ref from, .. } => from == original,
+                _ => false,
+            })
+            .count();
+        format!("{} clone {}", original, clones + 1)
+    }
+}
+
+impl fmt::Display for Log {
+    /// One event per line.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for event in self.events.borrow().iter() {
+            writeln!(f, "{}", event)?;
+        }
+        Ok(())
+    }
+}
+
+/// A value whose creation, clones and drop end up in a Log. It derefs to
+/// the value, so it can mostly be used as if it were the value itself.
+pub struct Tracked<T> {
+    label: String,
+    value: T,
+    log: Log,
+}
+
+impl<T> Tracked<T> {
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+impl<T: Clone> Clone for Tracked<T> {
+    fn clone(&self) -> Tracked<T> {
+        let label = self.log.clone_label(&self.label);
+        self.log.push(Event::Cloned {
+            original: self.label.clone(),
+            clone: label.clone(),
+        });
+        Tracked {
+            label,
+            value: self.value.clone(),
+            log: self.log.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Tracked<T> {
+    fn drop(&mut self) {
+        self.log.push(Event::Dropped(self.label.clone()));
+    }
+}
+
+impl<T> Deref for Tracked<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Tracked<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Tracked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {:?}", self.label, self.value)
+    }
+}
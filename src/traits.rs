@@ -0,0 +1,246 @@
+// Examples here only exist to be printed, so some fields are never read and
+// some calls (like clone() on a Copy type) are there to make a point.
+#![allow(dead_code)]
+#![allow(clippy::clone_on_copy)]
+
+use area;
+use lesson::{NotesLesson, Registry};
+use output::Output;
+use quiz::{Answer, Question};
+use source::Source;
+
+/// This file, so lessons can show their code next to what it prints.
+pub const SOURCE: Source = Source {
+    file: file!(),
+    text: include_str!("traits.rs"),
+};
+
+pub fn register(registry: &mut Registry) {
+    registry.add(
+        NotesLesson::new(
+            "traits",
+            "traits",
+            "Traits, derive, operators, Drop and Iterator",
+            _traits,
+        )
+        .tags(&["traits", "polymorphism", "operators", "drop", "iterators"])
+        .source(SOURCE)
+        .questions(&[
+            Question {
+                prompt: "What area does the Circle with radius 100.1 report?",
+                answer: Answer::Word {
+                    line: "Circle area:",
+                    index: 2,
+                },
+            },
+            Question {
+                prompt: "Which DropStruct is dropped first? (type what drop() prints)",
+                answer: Answer::LineAfter("leaving innermost scope"),
+            },
+        ]),
+    );
+}
+
+pub fn _traits(out: &Output) {
+    // traits are similar to interfaces (think Java)
+    // (shapes/mod.rs has the Shape the rest of the crate uses, with more
+    // shapes and f64 areas.)
+    trait Shape {
+        fn area(&self) -> u32;
+    }
+
+    struct Rectangle {
+        x: u32,
+        y: u32,
+    }
+
+    struct Circle {
+        radius: f64,
+    }
+
+    // a struct can implement a trait by fulfilling its required methods.
+    // self.x * self.y would panic (or wrap around in a release build) when
+    // the area does not fit in a u32, so this stops at u32::MAX instead.
+    impl Shape for Rectangle {
+        fn area(&self) -> u32 {
+            area::saturating(self.x, self.y)
+        }
+    }
+
+    // the standard library has pi, more precisely than typing out 3.141.
+    use std::f64::consts::PI;
+
+    impl Shape for Circle {
+        fn area(&self) -> u32 {
+            // `as u32` would do the same, but quietly.
+            area::saturating_to_u32(PI * self.radius * self.radius)
+        }
+    }
+
+    outln!(out, "Basic trait example with shape area() implementation:");
+    let c = Circle { radius: 100.1 };
+    let r = Rectangle { x: 30, y: 20 };
+    outln!(out, "Circle area: {} Rectangle area: {}", c.area(), r.area());
+
+    // when stopping at u32::MAX is not good enough, the checked version says
+    // the area did not fit, and the widening one uses a bigger type.
+    let huge = Rectangle { x: 70_000, y: 70_000 };
+    outln!(out, "Saturating area of 70000x70000: {}", huge.area());
+    match area::checked(huge.x, huge.y) {
+        Ok(area) => outln!(out, "Checked area: {}", area),
+        Err(err) => outln!(out, "Checked area: {}", err),
+    }
+    outln!(out, "Widening area: {}", area::widening(huge.x, huge.y));
+
+    // a collection of different shapes needs one type for all of them:
+    // Box<dyn Shape> (a trait object, with area() looked up at runtime) or
+    // an enum listing every kind. shapes::scene has both.
+
+    // derive annotatino can also be used to implement traits with basic
+    // implementations that are provided by the compiler.
+    // Clone vs Copy: StackOverflow discussion summarizes the difference as:
+    // Clone can be used for an arbitrarily complex duplication (deep copy,
+    // shallow copy, whatever). Copy is a shallow (reference, memcpy) copy.
+    // https://stackoverflow.com/questions/31012923/what-is-the-difference-between-copy-and-clone
+    #[derive(Debug, Clone, Copy)]
+    struct Alpha(i32);
+
+    // Comparison operators
+    // #[derive(Eq, PartialEq, PartialOrd, Ord)]
+    struct Beta(f32);
+
+    let _a = Alpha(32);
+    let _b = Beta(12.13);
+    // c would get ownership of the value that a owns (a loses its value
+    // and can't be used until c gives the value back).
+    // let c = a;
+
+    // we can use clone to avoid giving away ownership. c will get a clone of a.
+    let _c = _a.clone();
+    outln!(out, "{:?}", _a);
+
+    // The Copy trait basically does the same thing as Clone except that it
+    // happens any time something tries to borrow from a (you do not call clone(),
+    // but you can no longer control when NOT to clone).
+    // Think Java - Copy causes you to "opt-in" to Java style references that can
+    // be used after passing them to a function.
+    let _d = _a;
+    outln!(out, "{:?}", _a);
+
+    // traits can override basic operators (think of + - * /).
+    use std::ops;
+    struct A;
+    struct B;
+    #[derive(Debug)]
+    struct AB;
+    #[derive(Debug)]
+    struct BA;
+
+    impl ops::Add<B> for A {
+        type Output = AB;
+
+        fn add(self, _rhs: B) -> AB {
+            AB
+        }
+    }
+
+    impl ops::Add<A> for B {
+        type Output = BA;
+
+        fn add(self, _rhs: A) -> BA {
+            BA
+        }
+    }
+
+    outln!(out, "Trait overriding ops::Add(): ");
+    outln!(out, "{:?}", A + B);
+    outln!(out, "{:?}", B + A);
+
+    // shapes::transform uses the same traits to give points and vectors
+    // their arithmetic.
+
+    // implement Drop, which is a function (from a trait) automatically called
+    // when a variable gets dropped. A variable is dropped when it leaves scope.
+    // DropStruct holds on to the lesson output so drop() has somewhere to
+    // print to.
+    struct DropStruct<'a, 'b: 'a> {
+        a: String,
+        out: &'a Output<'b>,
+    }
+
+    impl<'a, 'b> Drop for DropStruct<'a, 'b> {
+        fn drop(&mut self) {
+            outln!(self.out, "dropped {}", self.a)
+        }
+    }
+
+    outln!(out, "Drop example:");
+    let _outer = DropStruct {
+        a: String::from("Outer scope variable"),
+        out,
+    };
+    {
+        let _middle = DropStruct {
+            a: String::from("Middle scope variable"),
+            out,
+        };
+        {
+            let _inner = DropStruct {
+                a: String::from("Inner scope variable"),
+                out,
+            };
+            outln!(out, "leaving innermost scope");
+        }
+        outln!(out, "leaving middle scope");
+    }
+    // drop function can be called to explicitly drop a variable.
+    drop(_outer);
+    outln!(out, "End of drop example.");
+
+    // Iterator trait can be implemented. Used in for loops, allowing
+    // iteration over a collection.
+    outln!(out, "Start Iterator example: ");
+    struct Fib {
+        c: u32,
+        n: u32,
+    }
+
+    impl Iterator for Fib {
+        type Item = u32;
+
+        fn next(&mut self) -> Option<u32> {
+            let n = self.c + self.n;
+            self.c = self.n;
+            self.n = n;
+
+            Some(self.c)
+        }
+    }
+
+    fn fib() -> Fib {
+        Fib { c: 1, n: 1 }
+    }
+
+    // Because we implemented the Iterator trait, Fib also inherits the take()
+    // and skip() methods.
+    for j in fib().take(10) {
+        outln!(out, "{}", j);
+    }
+
+    outln!(out, "skip method example: ");
+
+    for j in fib().skip(14).take(10) {
+        outln!(out, "{}", j);
+    }
+
+    // example of manually calling the next() method
+    outln!(out, "next method example: ",);
+    let mut f = fib();
+    outln!(out, "{:?}", f.next());
+    outln!(out, "{:?}", f.next());
+    outln!(out, "{:?}", f.next());
+    outln!(out, "{:?}", f.next());
+    outln!(out, "{:?}", f.next());
+
+    outln!(out, "End Iterator examples");
+}
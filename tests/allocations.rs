@@ -0,0 +1,53 @@
+extern crate rust_playground;
+
+use rust_playground::allocations::{self, Counting};
+use rust_playground::lesson::{Lesson, NotesLesson};
+use rust_playground::output::{self, Output};
+use rust_playground::runner;
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[test]
+fn pushing_onto_a_full_vec_reallocates() {
+    assert!(allocations::is_counting());
+
+    let mut v = Vec::with_capacity(4);
+    v.extend_from_slice(&[5, 6, 7, 8]);
+    let (_, stats) = allocations::measure(|| v.push(9));
+    assert_eq!((stats.allocations, stats.reallocations), (0, 1));
+    assert!(stats.bytes > 0);
+
+    // there is room now, so the next push does not touch the heap.
+    let (_, stats) = allocations::measure(|| v.push(10));
+    assert_eq!(stats, Default::default());
+}
+
+#[test]
+fn counts_allocations_and_frees() {
+    let (length, stats) = allocations::measure(|| {
+        let text = String::from("hello");
+        let boxed = Box::new([0u64; 4]);
+        text.len() + boxed.len()
+    });
+    assert_eq!(length, 9);
+    assert_eq!(stats.allocations, 2);
+    assert_eq!(stats.deallocations, 2);
+    assert_eq!(stats.bytes, 5 + 32);
+}
+
+fn allocates(_: &Output) {
+    let v: Vec<u8> = Vec::with_capacity(100);
+    assert_eq!(v.capacity(), 100);
+}
+
+#[test]
+fn reports_say_what_each_lesson_allocated() {
+    let lesson = NotesLesson::new("test", "allocates", "Allocates", allocates);
+    let mut report = None;
+    output::capture(|out| report = Some(runner::run_lesson(&lesson as &dyn Lesson, out)));
+
+    let stats = report.unwrap().allocations.unwrap();
+    assert!(stats.allocations >= 1);
+    assert!(stats.bytes >= 100);
+}
@@ -0,0 +1,62 @@
+extern crate rust_playground;
+
+use rust_playground::annotate;
+use rust_playground::lesson::Registry;
+use rust_playground::output::Output;
+use rust_playground::source::Source;
+
+const TRICKY: Source = Source {
+    file: "tricky.rs",
+    text: "fn before() {}
+
+/// Doc comments come along.
+pub fn tricky() {
+    let s = \"}}} not a brace\"; // nor } this
+    let c = '}';
+    let e = '\\'';
+    /* } */
+    fn inner<'a>(x: &'a str) -> &'a str { x }
+}
+
+fn after() {}
+",
+};
+
+#[test]
+fn finds_a_function_despite_braces_in_strings_and_comments() {
+    let function = TRICKY.function("tricky").unwrap();
+
+    assert_eq!(function.first_line, 3);
+    assert_eq!(function.lines.first(), Some(&"/// Doc comments come along."));
+    assert_eq!(function.lines.last(), Some(&"}"));
+    assert_eq!(function.lines.len(), 8);
+    assert!(TRICKY.function("missing").is_none());
+}
+
+#[test]
+fn every_lesson_has_its_source() {
+    for lesson in Registry::builtin().iter() {
+        let function = lesson.function();
+        assert!(function.is_some(), "no source for {}", lesson.full_name());
+        assert_eq!(function.unwrap().lines.last().map(|l| l.trim()), Some("}"));
+    }
+}
+
+#[test]
+fn output_appears_under_the_line_that_printed_it() {
+    let registry = Registry::builtin();
+    let lesson = registry.find("intro_1::strings").unwrap();
+
+    let mut buffer = Vec::new();
+    let out = Output::recording(&mut buffer);
+    lesson.run(&out);
+    let text = annotate::render(lesson, &out.take_printed());
+
+    let lines: Vec<&str> = text.lines().collect();
+    let call = lines
+        .iter()
+        .position(|line| line.ends_with("outln!(out, \"{}\", combined_string);"))
+        .unwrap();
+    assert!(lines[call + 1].ends_with("| > Hello World!"));
+    assert!(text.contains("// string concatenation example."));
+}
@@ -0,0 +1,48 @@
+extern crate rust_playground;
+
+use std::f64;
+
+use rust_playground::area::{self, AreaError};
+use rust_playground::shapes::{Circle, Rectangle, Shape};
+
+#[test]
+fn integer_areas_never_overflow_silently() {
+    assert_eq!(area::checked(30, 20), Ok(600));
+    assert_eq!(
+        area::checked(70_000, 70_000),
+        Err(AreaError::Overflow { width: 70_000, height: 70_000 })
+    );
+    assert_eq!(area::saturating(70_000, 70_000), u32::MAX);
+    assert_eq!(area::widening(70_000, 70_000), 4_900_000_000);
+    assert_eq!(area::widening(u32::MAX, u32::MAX), 18_446_744_065_119_617_025);
+
+    assert!(area::checked_u64(u64::MAX, 2).is_err());
+    assert_eq!(area::widening_u64(u64::MAX, 2), u128::from(u64::MAX) * 2);
+}
+
+#[test]
+fn float_areas_convert_with_errors_instead_of_guessing() {
+    assert_eq!(area::checked_to_u32(31_478.79), Ok(31_478));
+    assert_eq!(area::checked_to_u32(5e9), Err(AreaError::TooLarge(5e9)));
+    assert_eq!(area::checked_to_u32(-1.0), Err(AreaError::Negative(-1.0)));
+    assert!(match area::checked_to_u32(f64::NAN) {
+        Err(AreaError::NotFinite(area)) => area.is_nan(),
+        _ => false,
+    });
+
+    assert_eq!(area::saturating_to_u32(5e9), u32::MAX);
+    assert_eq!(area::saturating_to_u32(f64::INFINITY), u32::MAX);
+    assert_eq!(area::saturating_to_u32(f64::NAN), 0);
+    assert_eq!(area::saturating_to_u32(-3.0), 0);
+}
+
+#[test]
+fn shapes_check_their_areas() {
+    assert_eq!(Rectangle::new(30.0, 20.0).checked_area(), Ok(600.0));
+    assert_eq!(
+        Rectangle::new(1e200, 1e200).checked_area(),
+        Err(AreaError::NotFinite(f64::INFINITY))
+    );
+    assert_eq!(Rectangle::new(-2.0, 3.0).checked_area(), Err(AreaError::Negative(-6.0)));
+    assert!(Circle::new(1e300).checked_area().is_err());
+}
@@ -0,0 +1,45 @@
+extern crate rust_playground;
+
+use rust_playground::shapes::ascii::{self, Canvas, CanvasError, Mode};
+use rust_playground::shapes::{AnyShape, Circle, Placed, Point, Rectangle, Square};
+
+#[test]
+fn draws_filled_and_outlined_rectangles() {
+    let shapes: Vec<AnyShape> = vec![Rectangle::new(8.0, 8.0).into()];
+    assert_eq!(ascii::render(&shapes, 1.0, Mode::Filled).unwrap(), "########\n".repeat(4));
+    assert_eq!(
+        ascii::render(&shapes, 1.0, Mode::Outline).unwrap(),
+        "########\n#      #\n#      #\n########\n"
+    );
+    // twice the scale, half the characters each way.
+    assert_eq!(ascii::render(&shapes, 2.0, Mode::Filled).unwrap(), "####\n####\n");
+}
+
+#[test]
+fn circles_stay_round() {
+    // 8 units across is 8 columns, 8 units down is 4 rows.
+    let drawing = ascii::render(&[Circle::new(4.0).into()], 1.0, Mode::Filled).unwrap();
+    assert_eq!(drawing, " ######\n########\n########\n ######\n");
+}
+
+#[test]
+fn later_shapes_are_drawn_on_top() {
+    let mut canvas = Canvas::new(6, 2, 1.0);
+    canvas.draw(&Placed::new(Rectangle::new(6.0, 4.0), Point::new(0.0, 0.0)), Mode::Filled, '#');
+    canvas.draw(&Placed::new(Square::new(2.0), Point::new(2.0, 0.0)), Mode::Filled, '*');
+    assert_eq!(canvas.to_string(), "##**##\n######\n");
+    assert_eq!(canvas.get(2, 0), Some('*'));
+    assert_eq!(canvas.get(6, 0), None);
+}
+
+#[test]
+fn refuses_canvases_too_big_to_draw() {
+    let shapes: Vec<AnyShape> = vec![Rectangle::new(30.0, 20.0).into()];
+    match ascii::render(&shapes, 1e-9, Mode::Filled) {
+        Err(CanvasError::TooBig { columns, rows }) => assert!(columns > 500.0 && rows > 500.0),
+        other => panic!("expected TooBig, got {:?}", other),
+    }
+    // 500 columns is still fine.
+    let drawing = ascii::render(&[Rectangle::new(500.0, 2.0).into()], 1.0, Mode::Filled).unwrap();
+    assert_eq!(drawing, format!("{}\n", "#".repeat(500)));
+}
@@ -0,0 +1,56 @@
+extern crate rust_playground;
+
+use std::cell::Cell;
+use std::time::Duration;
+
+use rust_playground::bench::{self, Measurement, Options, Table};
+
+fn millis(samples: &[u64]) -> Measurement {
+    Measurement {
+        name: String::from("example"),
+        items: 1000,
+        samples: samples.iter().map(|&ms| Duration::from_millis(ms)).collect(),
+    }
+}
+
+#[test]
+fn statistics() {
+    let m = millis(&[4, 2, 8, 6]);
+    assert_eq!(m.median(), Duration::from_millis(5));
+    assert_eq!(m.mean(), Duration::from_millis(5));
+    // sqrt((1 + 9 + 9 + 1) / 3)
+    assert!((m.stddev().as_secs_f64() - (20.0f64 / 3.0).sqrt() / 1000.0).abs() < 1e-9);
+    assert!((m.nanos_per_item() - 5000.0).abs() < 1e-6);
+
+    assert_eq!(millis(&[9, 1, 3]).median(), Duration::from_millis(3));
+    assert_eq!(millis(&[3]).stddev(), Duration::from_secs(0));
+    assert_eq!(millis(&[]).median(), Duration::from_secs(0));
+}
+
+#[test]
+fn warms_up_before_measuring() {
+    let calls = Cell::new(0);
+    let options = Options {
+        warmup: 2,
+        iterations: 5,
+    };
+    let m = bench::measure("count", 1, options, || calls.set(calls.get() + 1));
+    assert_eq!(calls.get(), 7);
+    assert_eq!((m.name.as_str(), m.samples.len()), ("count", 5));
+}
+
+#[test]
+fn compares_every_kind_of_dispatch() {
+    let options = Options {
+        warmup: 0,
+        iterations: 2,
+    };
+    let measurements = bench::dispatch(100, options);
+    let names: Vec<&str> = measurements.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, ["enum (match)", "trait object (dyn)", "generic (static)"]);
+
+    let table = Table(&measurements).to_string();
+    assert!(table.starts_with("benchmark"));
+    assert_eq!(table.lines().count(), 4);
+    assert!(table.lines().any(|line| line.ends_with(" 1.00x")));
+}
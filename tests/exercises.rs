@@ -0,0 +1,73 @@
+extern crate rust_playground;
+
+use std::path::Path;
+
+use rust_playground::exercises::{self, Exercise, Status};
+use rust_playground::lesson::Registry;
+
+#[test]
+fn every_exercise_practices_a_lesson() {
+    let registry = Registry::builtin();
+    for exercise in exercises::builtin() {
+        assert!(registry.find(exercise.lesson).is_some(), "{}: no lesson {}", exercise.name, exercise.lesson);
+        assert!(Path::new(exercise.file).is_file(), "{}: no file {}", exercise.name, exercise.file);
+        assert!(!exercise.hint.is_empty());
+    }
+}
+
+fn solved() {}
+
+fn unfinished() {
+    todo!()
+}
+
+fn wrong() {
+    assert_eq!(1 + 1, 3, "one plus one");
+}
+
+fn exercise(name: &'static str, check: fn()) -> Exercise {
+    Exercise {
+        name,
+        lesson: "intro_1::ownership",
+        file: "src/exercises/ownership.rs",
+        hint: "",
+        check,
+    }
+}
+
+#[test]
+fn checks_tell_todo_from_wrong_answers() {
+    assert_eq!(exercises::check(&exercise("solved", solved)), Status::Solved);
+    match exercises::check(&exercise("unfinished", unfinished)) {
+        Status::Todo(Some(_)) => {}
+        other => panic!("expected a todo, got {:?}", other),
+    }
+    match exercises::check(&exercise("wrong", wrong)) {
+        Status::Failed(ref message) => assert!(message.contains("one plus one"), "{}", message),
+        other => panic!("expected a failure, got {:?}", other),
+    }
+
+    let list = vec![exercise("solved", solved), exercise("wrong", wrong), exercise("unfinished", unfinished)];
+    let (next, _) = exercises::next(&list).unwrap();
+    assert_eq!(next.name, "wrong");
+    assert!(exercises::next(&list[..1]).is_none());
+}
+
+#[test]
+fn checks_on_several_threads_keep_their_own_lines() {
+    let threads: Vec<_> = (0..8)
+        .map(|i| {
+            std::thread::spawn(move || {
+                let check: fn() = if i % 2 == 0 { unfinished } else { wrong };
+                exercises::check(&exercise("threaded", check))
+            })
+        })
+        .collect();
+    for (i, thread) in threads.into_iter().enumerate() {
+        match (i % 2, thread.join().unwrap()) {
+            (0, Status::Todo(Some(_))) => {}
+            (1, Status::Failed(ref message)) if message.contains("(line ") => {}
+            (_, other) => panic!("thread {}: unexpected {:?}", i, other),
+        }
+    }
+}
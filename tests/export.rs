@@ -0,0 +1,49 @@
+extern crate rust_playground;
+
+use rust_playground::export::{self, Block};
+use rust_playground::lesson::Registry;
+
+#[test]
+fn comments_become_prose_and_output_follows_its_code() {
+    let registry = Registry::builtin();
+    let section = export::section(registry.find("intro_1::strings").unwrap());
+
+    assert_eq!(section.full_name, "intro_1::strings");
+    assert_eq!(
+        section.blocks.last(),
+        Some(&Block::Output(String::from("Hello World!")))
+    );
+    let prose = section.blocks.iter().position(|block| {
+        *block == Block::Prose(String::from("string concatenation example. Note the reference (&another_string)."))
+    });
+    match section.blocks[prose.unwrap() + 1] {
+        Block::Code(ref code) => assert!(code.starts_with("let another_string")),
+        ref other => panic!("expected code after the prose, got {:?}", other),
+    }
+}
+
+#[test]
+fn commented_out_code_stays_in_code_blocks() {
+    let registry = Registry::builtin();
+    let section = export::section(registry.find("intro_1::control_flow").unwrap());
+
+    assert!(section.blocks.contains(&Block::Code(String::from(
+        "// loop {\n//     println!(\"infinite loop\");\n// }"
+    ))));
+}
+
+#[test]
+fn markdown_and_html_have_every_lesson_in_the_module() {
+    let registry = Registry::builtin();
+    let sections = export::sections(&registry, "intro_1");
+    let markdown = export::markdown("intro_1", &sections);
+    let html = export::html("intro_1", &sections);
+
+    assert_eq!(sections.len(), registry.in_module("intro_1").count());
+    assert!(markdown.starts_with("# intro_1\n"));
+    assert!(markdown.contains("## Ownership, moving, copying and borrowing (`intro_1::ownership`)"));
+    assert!(markdown.contains("```text\nHello World!\n```"));
+    assert!(html.contains("<h2>String and &amp;str (<code>intro_1::strings</code>)</h2>"));
+    assert!(html.contains("fn move_v(out: &amp;Output, v: Vec&lt;i32&gt;)"));
+    assert!(!html.contains("<link") && !html.contains("<script"));
+}
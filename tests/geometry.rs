@@ -0,0 +1,103 @@
+extern crate rust_playground;
+
+use std::f64::consts::PI;
+
+use rust_playground::shapes::geometry::Bounds;
+use rust_playground::shapes::{Circle, Placed, Point, Rectangle, Square, Triangle};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+fn rect(x: f64, y: f64, width: f64, height: f64) -> Placed {
+    Placed::new(Rectangle::new(width, height), Point::new(x, y))
+}
+
+fn circle(x: f64, y: f64, radius: f64) -> Placed {
+    Placed::new(Circle::new(radius), Point::new(x, y))
+}
+
+#[test]
+fn bounds_and_points() {
+    assert_eq!(
+        circle(5.0, 5.0, 2.0).bounds(),
+        Bounds::new(Point::new(3.0, 3.0), Point::new(7.0, 7.0))
+    );
+    let triangle = Placed::new(
+        Triangle::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 3.0)),
+        Point::new(10.0, 10.0),
+    );
+    assert_eq!(triangle.bounds().area(), 12.0);
+    assert_eq!(
+        Bounds::around(&[triangle, rect(0.0, 0.0, 1.0, 1.0)]),
+        Some(Bounds::new(Point::new(0.0, 0.0), Point::new(14.0, 13.0)))
+    );
+    assert_eq!(Bounds::around(&[]), None);
+
+    // edges count as inside.
+    assert!(rect(0.0, 0.0, 30.0, 20.0).contains(Point::new(30.0, 20.0)));
+    assert!(!rect(0.0, 0.0, 30.0, 20.0).contains(Point::new(31.0, 0.0)));
+    assert!(circle(0.0, 0.0, 5.0).contains(Point::new(3.0, 4.0)));
+    assert!(!circle(0.0, 0.0, 5.0).contains(Point::new(4.0, 4.0)));
+    assert!(triangle.contains(Point::new(11.0, 11.0)));
+    assert!(!triangle.contains(Point::new(13.0, 12.0)));
+}
+
+#[test]
+fn intersections() {
+    let a = rect(0.0, 0.0, 10.0, 10.0);
+    assert_eq!(a.intersects(&rect(5.0, 5.0, 10.0, 10.0)), Some(true));
+    // touching edges intersect, without any area in common.
+    assert_eq!(a.intersects(&rect(10.0, 0.0, 10.0, 10.0)), Some(true));
+    assert_eq!(a.overlap_area(&rect(10.0, 0.0, 10.0, 10.0)), Some(0.0));
+    assert_eq!(a.intersects(&rect(11.0, 0.0, 10.0, 10.0)), Some(false));
+
+    assert_eq!(circle(0.0, 0.0, 3.0).intersects(&circle(5.0, 0.0, 2.0)), Some(true));
+    assert_eq!(circle(0.0, 0.0, 3.0).intersects(&circle(5.1, 0.0, 2.0)), Some(false));
+
+    // near the corner, but outside the circle's reach.
+    assert_eq!(a.intersects(&circle(13.0, 13.0, 4.0)), Some(false));
+    assert_eq!(circle(13.0, 13.0, 5.0).intersects(&a), Some(true));
+
+    let triangle = Placed::new(
+        Triangle::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 3.0)),
+        Point::new(0.0, 0.0),
+    );
+    assert_eq!(a.intersects(&triangle), None);
+    assert_eq!(triangle.overlap_area(&a), None);
+}
+
+#[test]
+fn overlap_areas() {
+    let square = Placed::new(Square::new(10.0), Point::new(0.0, 0.0));
+    assert_eq!(square.overlap_area(&rect(5.0, -5.0, 10.0, 10.0)), Some(25.0));
+
+    // a circle inside another, half-overlapping circles, and apart.
+    assert!(close(circle(0.0, 0.0, 5.0).overlap_area(&circle(1.0, 0.0, 2.0)).unwrap(), 4.0 * PI));
+    let lens = circle(0.0, 0.0, 1.0).overlap_area(&circle(1.0, 0.0, 1.0)).unwrap();
+    assert!(close(lens, 2.0 * PI / 3.0 - 3f64.sqrt() / 2.0));
+    assert_eq!(circle(0.0, 0.0, 1.0).overlap_area(&circle(3.0, 0.0, 1.0)), Some(0.0));
+
+    // a rectangle around the circle, a quarter of it, half of it and a
+    // rectangle inside it.
+    let c = circle(0.0, 0.0, 2.0);
+    assert!(close(c.overlap_area(&rect(-5.0, -5.0, 10.0, 10.0)).unwrap(), 4.0 * PI));
+    assert!(close(c.overlap_area(&rect(0.0, 0.0, 10.0, 10.0)).unwrap(), PI));
+    assert!(close(rect(-3.0, -3.0, 3.0, 6.0).overlap_area(&c).unwrap(), 2.0 * PI));
+    assert!(close(c.overlap_area(&rect(-1.0, -1.0, 2.0, 2.0)).unwrap(), 4.0));
+    // the segment to the right of x = 1.
+    let segment = 4.0 * PI / 3.0 - 3f64.sqrt();
+    assert!(close(c.overlap_area(&rect(1.0, -5.0, 10.0, 10.0)).unwrap(), segment));
+    assert_eq!(c.overlap_area(&rect(5.0, 5.0, 1.0, 1.0)), Some(0.0));
+}
+
+#[test]
+fn flat_triangles_contain_nothing() {
+    let origin = Point::new(0.0, 0.0);
+    let point = Placed::new(Triangle::new(origin, origin, origin), origin);
+    assert!(!point.contains(origin));
+    assert!(!point.contains(Point::new(3.0, -7.0)));
+    let line = Placed::new(Triangle::new(origin, Point::new(2.0, 2.0), Point::new(4.0, 4.0)), origin);
+    assert!(!line.contains(Point::new(1.0, 1.0)));
+    assert!(!line.contains(Point::new(10.0, 10.0)));
+}
@@ -0,0 +1,63 @@
+// Rust lays out types without #[repr(C)] however it likes, so these only
+// check what holds on every compiler and target.
+
+extern crate rust_playground;
+
+use std::mem;
+
+use rust_playground::layout::{self, Layout, Table};
+use rust_playground::lesson::Registry;
+use rust_playground::snapshot;
+
+fn find(layouts: &[Layout], name: &str) -> Layout {
+    layouts.iter().find(|layout| layout.name == name).unwrap().clone()
+}
+
+#[test]
+fn fields_are_listed_in_memory_order() {
+    for layout in layout::layouts() {
+        assert!(layout.fields.windows(2).all(|pair| pair[0].offset < pair[1].offset), "{}", layout.name);
+        let fields: usize = layout.fields.iter().map(|field| field.size).sum();
+        assert!(layout.size >= fields, "{}", layout.name);
+        assert!(layout.fields.iter().all(|field| field.offset + field.size <= layout.size));
+    }
+    let rectangle = find(&layout::layouts(), "Rectangle");
+    assert_eq!(rectangle.size, 2 * mem::size_of::<u32>() + rectangle.padding().unwrap());
+}
+
+#[test]
+fn option_uses_niches_when_there_are_any() {
+    let layouts = layout::layouts();
+    assert!(find(&layouts, "&String").has_niche());
+    assert!(!find(&layouts, "f64").has_niche());
+    assert!(!find(&layouts, "Point").has_niche());
+    assert_eq!(mem::size_of::<Option<&String>>(), mem::size_of::<&String>());
+    assert!(layouts.iter().all(|layout| layout.option_size >= layout.size));
+}
+
+#[test]
+fn table_shows_every_type_and_note() {
+    let layouts = layout::layouts();
+    let table = Table(&layouts).to_string();
+    for layout in &layouts {
+        assert!(table.contains(&format!("\n{:<16}", layout.name)), "{} is missing", layout.name);
+        assert!(table.contains(layout.note));
+    }
+    assert!(table.contains("  .height        offset "));
+    assert!(!table.contains("Option<&String>"));
+}
+
+#[test]
+fn layout_lesson_has_no_snapshot() {
+    let registry = Registry::builtin();
+    let lesson = registry.find("layout::lesson_types").unwrap();
+    assert!(lesson.has_tag(snapshot::MACHINE_SPECIFIC));
+}
+
+#[test]
+fn lesson_source_is_the_lesson_body() {
+    let registry = Registry::builtin();
+    let function = registry.find("layout::lesson_types").unwrap().function().unwrap();
+    assert!(function.lines.iter().any(|line| line.contains("pub fn _lesson_types(")));
+    assert!(function.lines.iter().any(|line| line.contains("outln!(out,")));
+}
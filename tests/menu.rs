@@ -0,0 +1,64 @@
+extern crate rust_playground;
+
+use std::io::Cursor;
+
+use rust_playground::fuzzy;
+use rust_playground::lesson::Registry;
+use rust_playground::menu::{self, Choice};
+
+#[test]
+fn picks_lessons_by_number_name_or_fuzzy_name() {
+    let registry = Registry::builtin();
+
+    assert_eq!(menu::choose(&registry, "1"), Choice::Lesson(0));
+    assert_eq!(menu::choose(&registry, " intro_1::strings \n"), Choice::Lesson(3));
+    assert_eq!(menu::choose(&registry, "ownr"), Choice::Lesson(4));
+    assert_eq!(menu::choose(&registry, "ctrl flw"), Choice::Lesson(6));
+    assert_eq!(menu::choose(&registry, "generic"), Choice::Lesson(11));
+    assert_eq!(menu::choose(&registry, "0"), Choice::NotFound(String::from("0")));
+    assert_eq!(menu::choose(&registry, "99"), Choice::NotFound(String::from("99")));
+    assert_eq!(menu::choose(&registry, "xyz"), Choice::NotFound(String::from("xyz")));
+    assert_eq!(menu::choose(&registry, "n"), Choice::Next);
+    assert_eq!(menu::choose(&registry, ""), Choice::Nothing);
+}
+
+#[test]
+fn next_follows_reading_order() {
+    let registry = Registry::builtin();
+    let mut input = Cursor::new("n\nn\n4\nn\nq\n");
+    let mut screen = Vec::new();
+    let ran = menu::run(&registry, &mut input, &mut screen).unwrap();
+    let screen = String::from_utf8(screen).unwrap();
+
+    let headings: Vec<&str> = screen.lines().filter(|line| line.starts_with("== ")).collect();
+    assert_eq!(
+        headings,
+        vec![
+            "== intro_1::mutability: Variables and mutability ==",
+            "== intro_1::tuples: Tuples and debug printing ==",
+            "== intro_1::strings: String and &str ==",
+            "== intro_1::ownership: Ownership, moving, copying and borrowing ==",
+        ]
+    );
+    assert!(screen.contains("Hello World!"));
+    assert_eq!(
+        ran,
+        vec!["intro_1::mutability", "intro_1::tuples", "intro_1::strings", "intro_1::ownership"]
+    );
+}
+
+#[test]
+fn stops_at_the_end_of_input() {
+    let registry = Registry::builtin();
+    let mut screen = Vec::new();
+    menu::run(&registry, &mut Cursor::new(""), &mut screen).unwrap();
+
+    assert!(String::from_utf8(screen).unwrap().contains(" 1. mutability"));
+}
+
+#[test]
+fn fuzzy_prefers_matches_at_word_starts() {
+    assert!(fuzzy::score("cf", "control_flow") > fuzzy::score("cf", "cast_of"));
+    assert_eq!(fuzzy::score("xz", "control_flow"), None);
+    assert_eq!(fuzzy::rank("str", vec!["structures", "strings"]).len(), 2);
+}
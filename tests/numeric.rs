@@ -0,0 +1,50 @@
+extern crate rust_playground;
+
+use std::f64::consts::PI;
+
+use rust_playground::generics::{Circle, Rectangle, Shape};
+use rust_playground::numeric::{Fixed, Numeric};
+
+const RADII: [f64; 6] = [0.5, 1.0, 2.5, 10.0, 100.1, 1000.0];
+
+/// Largest relative error of Circle<T>::area over RADII.
+fn worst_error<T: Numeric>() -> f64 {
+    RADII
+        .iter()
+        .map(|&radius| {
+            let exact = PI * radius * radius;
+            let area = Circle { radius: T::from_f64(radius) }.area().to_f64();
+            ((area - exact) / exact).abs()
+        })
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn circle_area_is_accurate_for_every_number_type() {
+    assert!(worst_error::<f64>() < 1e-15, "f64: {}", worst_error::<f64>());
+    assert!(worst_error::<f32>() < 1e-6, "f32: {}", worst_error::<f32>());
+    assert!(worst_error::<Fixed>() < 1e-8, "Fixed: {}", worst_error::<Fixed>());
+}
+
+#[test]
+fn rectangles_only_need_multiplication() {
+    assert_eq!(Rectangle { x: 3, y: 4 }.area(), 12);
+    assert_eq!(Rectangle { x: 1.5f32, y: 4.0 }.area(), 6.0);
+    let fixed = Rectangle { x: Fixed::from_f64(2.5), y: Fixed::from_int(4) }.area();
+    assert_eq!(fixed, Fixed::from_int(10));
+}
+
+#[test]
+fn fixed_point_arithmetic() {
+    assert!((Fixed::PI.to_f64() - PI).abs() <= Fixed::epsilon());
+    assert_eq!(Fixed::from_int(3) * Fixed::from_f64(0.5), Fixed::from_f64(1.5));
+    assert_eq!(Fixed::from_int(1) / Fixed::from_int(4), Fixed::from_f64(0.25));
+    assert_eq!(Fixed::from_int(2) - Fixed::from_int(5), Fixed::from_int(-3));
+    assert_eq!(Fixed::ONE + Fixed::ZERO, Fixed::from_int(1));
+
+    // too big to hold saturates instead of wrapping around.
+    let big = Fixed::from_int(i32::MAX);
+    assert_eq!((big * big).to_bits(), i64::MAX);
+    assert_eq!((-big * big).to_bits(), i64::MIN);
+    assert_eq!(Fixed::from_f64(1e300).to_bits(), i64::MAX);
+}
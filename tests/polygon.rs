@@ -0,0 +1,84 @@
+extern crate rust_playground;
+
+use rust_playground::shapes::polygon::{Polygon, PolygonError, Winding};
+use rust_playground::shapes::{Point, Shape};
+
+fn points(coordinates: &[(f64, f64)]) -> Vec<Point> {
+    coordinates.iter().map(|&(x, y)| Point::new(x, y)).collect()
+}
+
+#[test]
+fn measures_polygons() {
+    // an L shape, clockwise.
+    let l = Polygon::new(points(&[(0.0, 0.0), (0.0, 3.0), (1.0, 3.0), (1.0, 1.0), (2.0, 1.0), (2.0, 0.0)])).unwrap();
+    assert_eq!(l.signed_area(), -4.0);
+    assert_eq!((l.area(), l.perimeter(), l.kind()), (4.0, 10.0, "polygon"));
+    assert_eq!(l.winding(), Winding::Clockwise);
+    assert!(!l.is_convex());
+    assert_eq!(l.centroid(), Point::new(0.75, 1.25));
+
+    let turned = l.clone().with_winding(Winding::Counterclockwise);
+    assert_eq!(turned.signed_area(), 4.0);
+    assert_eq!(turned.points()[0], l.points()[0]);
+    assert_eq!(turned.clone().with_winding(Winding::Counterclockwise), turned);
+
+    // a closing point is dropped, and a corner in the middle of an edge
+    // does not stop a square being convex.
+    let square = Polygon::new(points(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0), (0.0, 0.0)])).unwrap();
+    assert_eq!(square.points().len(), 5);
+    assert!(square.is_convex());
+    assert_eq!(square.centroid(), Point::new(1.0, 1.0));
+}
+
+#[test]
+fn convex_hulls() {
+    let cloud = points(&[(1.0, 1.0), (0.0, 0.0), (2.0, 0.0), (1.0, 0.0), (2.0, 2.0), (0.0, 2.0), (1.0, 0.5), (0.0, 0.0)]);
+    let hull = Polygon::convex_hull(&cloud).unwrap();
+    assert_eq!(hull.points(), &points(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)])[..]);
+    assert_eq!(hull.winding(), Winding::Counterclockwise);
+    assert!(hull.is_convex());
+
+    let l = Polygon::new(points(&[(0.0, 0.0), (0.0, 3.0), (1.0, 3.0), (1.0, 1.0), (2.0, 1.0), (2.0, 0.0)])).unwrap();
+    assert_eq!(l.hull().unwrap().area(), 5.0);
+
+    assert_eq!(
+        Polygon::convex_hull(&points(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)])),
+        Err(PolygonError::Collinear)
+    );
+    assert_eq!(
+        Polygon::convex_hull(&points(&[(0.0, 0.0), (1.0, 1.0), (0.0, 0.0)])),
+        Err(PolygonError::TooFewPoints(2))
+    );
+}
+
+#[test]
+fn rejects_bad_polygons() {
+    let error = |coordinates: &[(f64, f64)]| Polygon::new(points(coordinates)).unwrap_err();
+    assert_eq!(error(&[(0.0, 0.0), (1.0, 0.0)]), PolygonError::TooFewPoints(2));
+    assert_eq!(error(&[(0.0, 0.0), (1.0, 0.0), (1.0, 0.0), (0.0, 1.0)]), PolygonError::RepeatedPoint { index: 2 });
+    assert_eq!(error(&[(0.0, 0.0), (1.0, f64::NAN), (0.0, 1.0)]), PolygonError::NotFinite { index: 1 });
+    assert_eq!(error(&[(0.0, 0.0), (1.0, 1.0), (3.0, 3.0)]), PolygonError::Collinear);
+
+    // a bow tie, whose edges 0 and 2 cross in the middle.
+    let bow_tie = error(&[(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0)]);
+    assert_eq!(bow_tie, PolygonError::SelfIntersecting { first: 0, second: 2 });
+    assert_eq!(bow_tie.to_string(), "edges 0 and 2 cross each other");
+    // an edge doubling back along the one before it.
+    assert_eq!(
+        error(&[(0.0, 0.0), (2.0, 0.0), (1.0, 0.0), (1.0, 1.0)]),
+        PolygonError::SelfIntersecting { first: 0, second: 1 }
+    );
+    // a corner touching another edge.
+    assert_eq!(
+        error(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (2.0, 0.0), (0.0, 4.0)]),
+        PolygonError::SelfIntersecting { first: 0, second: 2 }
+    );
+}
+
+#[test]
+fn nearly_collinear_corners_have_no_hull() {
+    // not quite on one line after rounding, so new() takes them, but the
+    // hull's turns round to straight.
+    let sliver = Polygon::new(points(&[(5.7, 1.71), (8.0, 2.4), (0.6, 0.18)])).unwrap();
+    assert_eq!(sliver.hull(), Err(PolygonError::Collinear));
+}
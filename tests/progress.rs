@@ -0,0 +1,117 @@
+extern crate rust_playground;
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use rust_playground::lesson::Registry;
+use rust_playground::progress::{self, Error, Progress};
+use rust_playground::quiz::Score;
+
+fn temporary_file(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("rust_playground-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir.join("progress.txt")
+}
+
+#[test]
+fn saves_and_loads_again() {
+    let path = temporary_file("round-trip");
+    assert_eq!(Progress::load(&path).unwrap(), Progress::new());
+
+    let mut saved = Progress::new();
+    saved.record_run("intro_1::strings", 1_760_781_600);
+    saved.record_quiz("intro_1::control_flow", Score { correct: 2, asked: 2 }, 1_760_781_660);
+    saved.save(&path).unwrap();
+
+    let loaded = Progress::load(&path).unwrap();
+    assert_eq!(loaded, saved);
+    assert_eq!(loaded.last_run("intro_1::strings"), Some(1_760_781_600));
+    assert_eq!(loaded.quiz_passed("intro_1::control_flow"), Some(1_760_781_660));
+    fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn keeps_one_run_per_lesson() {
+    let mut progress = Progress::new();
+    for at in &[30, 10, 20] {
+        progress.record_run("intro_1::strings", *at);
+    }
+    assert_eq!(progress.runs.len(), 1);
+    assert_eq!(progress.last_run("intro_1::strings"), Some(30));
+
+    // older files with a line per run are merged when read.
+    let text = "rust_playground progress v1\nrun\ta\t5\nrun\ta\t9\nrun\tb\t1\nrun\ta\t7\n";
+    let progress = Progress::parse(text).unwrap();
+    assert_eq!(progress.to_string(), "rust_playground progress v1\nrun\ta\t9\nrun\tb\t1\n");
+}
+
+#[test]
+fn keeps_one_quiz_result_per_lesson() {
+    let (failed, passed) = (Score { correct: 1, asked: 2 }, Score { correct: 2, asked: 2 });
+    let mut progress = Progress::new();
+    progress.record_quiz("intro_1::strings", failed, 10);
+    progress.record_quiz("intro_1::strings", failed, 20);
+    assert_eq!(progress.quizzes.len(), 1);
+    assert_eq!(progress.quizzes[0].at, 20);
+
+    // once passed, later attempts do not move the date it was passed.
+    progress.record_quiz("intro_1::strings", passed, 30);
+    progress.record_quiz("intro_1::strings", failed, 40);
+    progress.record_quiz("intro_1::strings", passed, 50);
+    assert_eq!(progress.quizzes.len(), 1);
+    assert_eq!(progress.quiz_passed("intro_1::strings"), Some(30));
+
+    let text = "rust_playground progress v1\nquiz\ta\t0/2\t5\nquiz\ta\t2/2\t9\nquiz\ta\t1/2\t12\n";
+    let progress = Progress::parse(text).unwrap();
+    assert_eq!(progress.to_string(), "rust_playground progress v1\nquiz\ta\t2/2\t9\n");
+}
+
+#[test]
+fn leaves_newer_files_and_unknown_lines_alone() {
+    let path = temporary_file("newer");
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let newer = "rust_playground progress v99\nrun\tintro_1::strings\t1\n";
+    fs::write(&path, newer).unwrap();
+
+    match Progress::load(&path) {
+        Err(Error::NewerVersion(99)) => {}
+        other => panic!("expected a version error, got {:?}", other),
+    }
+    assert!(Progress::new().save(&path).is_err());
+    assert_eq!(fs::read_to_string(&path).unwrap(), newer);
+    fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+    let text = "rust_playground progress v1\nrun\tintro_1::strings\t1\nstreak\t3\n";
+    assert_eq!(Progress::parse(text).unwrap().to_string(), text);
+
+    match Progress::parse("rust_playground progress v1\nrun\tintro_1::strings\tyesterday\n") {
+        Err(Error::Parse { line: 2, .. }) => {}
+        other => panic!("expected a parse error on line 2, got {:?}", other),
+    }
+}
+
+#[test]
+fn suggests_the_next_lesson() {
+    let registry = Registry::builtin();
+    let mut progress = Progress::new();
+    assert_eq!(progress.next_lesson(&registry).unwrap().full_name(), "intro_1::mutability");
+
+    for lesson in registry.iter() {
+        progress.record_run(&lesson.full_name(), 0);
+    }
+    let first_quiz = registry.iter().find(|lesson| !lesson.questions().is_empty()).unwrap();
+    assert_eq!(progress.next_lesson(&registry).unwrap().full_name(), first_quiz.full_name());
+
+    let report = progress.report(&registry);
+    assert!(report.contains("intro_1: 10/10 lessons run"));
+    assert!(report.contains("[x] mutability, last run 1970-01-01 00:00 UTC"));
+    assert!(report.contains(&format!("next: {}", first_quiz.full_name())));
+}
+
+#[test]
+fn formats_times_as_utc_dates() {
+    assert_eq!(progress::format_time(0), "1970-01-01 00:00 UTC");
+    assert_eq!(progress::format_time(951_782_400), "2000-02-29 00:00 UTC");
+    assert_eq!(progress::format_time(1_760_781_600), "2025-10-18 10:00 UTC");
+}
@@ -0,0 +1,77 @@
+extern crate rust_playground;
+
+use std::io::Cursor;
+
+use rust_playground::lesson::Registry;
+use rust_playground::quiz::{self, Answer, Score};
+
+#[test]
+fn every_question_finds_its_answer_in_the_real_output() {
+    let registry = Registry::builtin();
+    let mut asked = 0;
+    for lesson in registry.iter() {
+        let output = lesson.capture();
+        for question in lesson.questions() {
+            assert!(
+                question.answer.find(&output).is_some(),
+                "{}: no answer for \"{}\"",
+                lesson.full_name(),
+                question.prompt
+            );
+            asked += 1;
+        }
+    }
+    assert!(asked > 0);
+}
+
+#[test]
+fn answers_come_from_the_output() {
+    let output = "first\nExtra matching example, mat2: 19\nThis is a \"teen\"\nn2 was assigned: 16\n24.4321 24 \u{18}\n";
+
+    assert_eq!(Answer::Line(0).find(output), Some(String::from("first")));
+    assert_eq!(
+        Answer::LineAfter("Extra matching example, mat2: 19").find(output),
+        Some(String::from("This is a \"teen\""))
+    );
+    assert_eq!(Answer::After("n2 was assigned: ").find(output), Some(String::from("16")));
+    assert_eq!(
+        Answer::Word { line: "24.4321", index: 2 }.find(output),
+        Some(String::from("\u{18}"))
+    );
+    assert_eq!(Answer::LineAfter("missing").find(output), None);
+}
+
+#[test]
+fn loose_about_case_spacing_quotes_and_escapes() {
+    assert!(quiz::is_correct("This is a \"teen\"", "this is a \"TEEN\""));
+    assert!(quiz::is_correct("[2, 3, 4]", "[2,3,4]"));
+    assert!(quiz::is_correct("\"fifty\"", "fifty"));
+    assert!(quiz::is_correct("\u{18}", "'\\u{18}'"));
+    assert!(!quiz::is_correct("16", "15"));
+    assert!(!quiz::is_correct("16", ""));
+}
+
+#[test]
+fn ask_keeps_score() {
+    let registry = Registry::builtin();
+    let lesson = registry.find("intro_1::control_flow").unwrap();
+    let mut input = Cursor::new("This is a \"teen\"\n17\n");
+    let mut screen = Vec::new();
+
+    let score = quiz::ask(lesson, &mut input, &mut screen).unwrap().unwrap();
+    let screen = String::from_utf8(screen).unwrap();
+
+    assert_eq!(score, Score { correct: 1, asked: 2 });
+    assert!(!score.passed());
+    assert!(screen.contains("not quite, it prints: 16"));
+}
+
+#[test]
+fn ask_stops_when_the_input_ends() {
+    let registry = Registry::builtin();
+    let lesson = registry.find("intro_1::control_flow").unwrap();
+    // one answer, then nothing: no score rather than a wrong answer.
+    let mut input = Cursor::new("This is a \"teen\"\n");
+    let mut screen = Vec::new();
+    assert_eq!(quiz::ask(lesson, &mut input, &mut screen).unwrap(), None);
+}
@@ -0,0 +1,57 @@
+#[macro_use]
+extern crate rust_playground;
+
+use rust_playground::lesson::{Lesson, NotesLesson};
+use rust_playground::output::{self, Output};
+use rust_playground::runner::{self, Outcome, Summary};
+
+fn prints_hello(out: &Output) {
+    outln!(out, "hello");
+}
+
+fn panics(out: &Output) {
+    outln!(out, "about to panic");
+    panic!("lesson {} went wrong", 1);
+}
+
+#[test]
+fn keeps_running_after_a_panic() {
+    let lessons = [
+        NotesLesson::new("test", "first", "First", prints_hello),
+        NotesLesson::new("test", "broken", "Broken", panics),
+        NotesLesson::new("test", "last", "Last", prints_hello),
+    ];
+
+    let mut reports = Vec::new();
+    let printed = output::capture(|out| {
+        reports = runner::run_all(lessons.iter().map(|l| l as &dyn Lesson), out);
+    });
+
+    assert_eq!(printed, "hello\nabout to panic\nhello\n");
+    assert_eq!(reports.len(), 3);
+    assert_eq!(reports[0].outcome, Outcome::Passed);
+    assert_eq!(
+        reports[1].outcome,
+        Outcome::Panicked(String::from("lesson 1 went wrong"))
+    );
+    assert_eq!(reports[2].outcome, Outcome::Passed);
+    assert_eq!(reports[1].lesson, "test::broken");
+    // this test does not install the counting allocator.
+    assert_eq!(reports[0].allocations, None);
+}
+
+#[test]
+fn summary_counts_panics() {
+    let lessons = [
+        NotesLesson::new("test", "first", "First", prints_hello),
+        NotesLesson::new("test", "broken", "Broken", panics),
+    ];
+    let printed = output::capture(|out| {
+        let reports = runner::run_all(lessons.iter().map(|l| l as &dyn Lesson), out);
+        assert_eq!(Summary(&reports).failed(), 1);
+        outln!(out, "{}", Summary(&reports));
+    });
+
+    assert!(printed.contains("lesson 1 went wrong"));
+    assert!(printed.ends_with("2 lesson(s): 1 passed, 1 panicked\n"));
+}
@@ -0,0 +1,69 @@
+extern crate rust_playground;
+
+use rust_playground::shapes::polygon::Polygon;
+use rust_playground::shapes::scene::{EnumScene, Group, Scene};
+use rust_playground::shapes::{AnyShape, Circle, Point, Rectangle, Shape, Square};
+
+fn enum_scene() -> EnumScene {
+    EnumScene::new()
+        .with(Rectangle::new(30.0, 20.0))
+        .with(Square::new(10.0))
+        .with(Circle::new(1.0))
+        .with(Rectangle::new(1.0, 2.0))
+}
+
+#[test]
+fn both_kinds_of_scene_agree() {
+    let inline = enum_scene();
+    let boxed = Scene::from(inline.clone());
+    assert_eq!((inline.len(), boxed.len()), (4, 4));
+    assert_eq!(inline.total_area(), boxed.total_area());
+    assert_eq!(inline.groups(), boxed.groups());
+    assert_eq!(inline.to_string(), boxed.to_string());
+    assert_eq!(inline.of_kind("rectangle").len(), 2);
+    assert_eq!(boxed.of_kind("rectangle").len(), 2);
+    assert!(boxed.of_kind("triangle").is_empty());
+    assert!(Scene::new().is_empty() && EnumScene::new().is_empty());
+}
+
+#[test]
+fn sorts_and_groups() {
+    let mut inline = enum_scene();
+    inline.sort_by_area();
+    let kinds: Vec<&str> = inline.shapes().iter().map(AnyShape::kind).collect();
+    assert_eq!(kinds, ["rectangle", "circle", "square", "rectangle"]);
+
+    let mut boxed = Scene::from(enum_scene());
+    boxed.sort_by_area();
+    let areas: Vec<f64> = boxed.shapes().iter().map(|shape| shape.area()).collect();
+    assert_eq!(areas, [2.0, std::f64::consts::PI, 100.0, 600.0]);
+
+    let groups = enum_scene().groups();
+    assert_eq!(
+        groups[0],
+        Group {
+            kind: "rectangle",
+            count: 2,
+            total_area: 602.0,
+            smallest: 2.0,
+            largest: 600.0,
+        }
+    );
+    assert_eq!(groups[0].mean_area(), 301.0);
+    assert_eq!(groups.iter().map(|group| group.kind).collect::<Vec<_>>(), ["rectangle", "square", "circle"]);
+}
+
+#[test]
+fn scenes_take_any_shape() {
+    let triangle = Polygon::new(vec![Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 3.0)]).unwrap();
+    let scene = Scene::new().with(triangle).with(Square::new(2.0));
+    let expected = "\
+shape              area    perimeter
+polygon            6.00        12.00
+square             4.00         8.00
+1 polygon(s): total 6.00, mean 6.00, smallest 6.00, largest 6.00
+1 square(s): total 4.00, mean 4.00, smallest 4.00, largest 4.00
+2 shape(s), total area 10.00
+";
+    assert_eq!(scene.to_string(), expected);
+}
@@ -0,0 +1,61 @@
+extern crate rust_playground;
+
+use std::f64::consts::PI;
+
+use rust_playground::shapes::{AnyShape, Circle, Point, Rectangle, Shape, Square, Triangle};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+fn examples() -> Vec<AnyShape> {
+    vec![
+        Rectangle::new(30.0, 20.0).into(),
+        Square::new(10.0).into(),
+        Circle::new(100.1).into(),
+        Triangle::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 3.0)).into(),
+    ]
+}
+
+#[test]
+fn areas_and_perimeters() {
+    let rectangle = Rectangle::new(30.0, 20.0);
+    assert_eq!((rectangle.area(), rectangle.perimeter()), (600.0, 100.0));
+
+    let square = Square::new(10.0);
+    assert_eq!((square.area(), square.perimeter()), (100.0, 40.0));
+    assert_eq!(square.to_rectangle().area(), square.area());
+
+    let circle = Circle::new(2.0);
+    assert!(close(circle.area(), 4.0 * PI));
+    assert!(close(circle.perimeter(), 4.0 * PI));
+
+    let triangle = Triangle::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 3.0));
+    assert_eq!((triangle.area(), triangle.perimeter()), (6.0, 12.0));
+}
+
+#[test]
+fn triangles_from_side_lengths() {
+    let triangle = Triangle::from_sides(3.0, 4.0, 5.0).unwrap();
+    let sides = triangle.sides();
+    assert!(close(sides[0], 3.0) && close(sides[1], 4.0) && close(sides[2], 5.0));
+    assert!(close(triangle.area(), 6.0));
+
+    assert_eq!(Triangle::from_sides(1.0, 2.0, 3.0), None);
+    assert_eq!(Triangle::from_sides(0.0, 1.0, 1.0), None);
+}
+
+#[test]
+fn trait_objects_and_the_enum_agree() {
+    let shapes = examples();
+    let boxed: Vec<Box<dyn Shape>> = shapes.iter().map(|shape| shape.boxed()).collect();
+
+    for (shape, object) in shapes.iter().zip(&boxed) {
+        assert_eq!(shape.kind(), object.kind());
+        assert_eq!(shape.area(), object.area());
+        assert_eq!(shape.perimeter(), object.perimeter());
+        assert_eq!(shape.as_shape().area(), shape.area());
+    }
+    let kinds: Vec<&str> = boxed.iter().map(|shape| shape.kind()).collect();
+    assert_eq!(kinds, ["rectangle", "square", "circle", "triangle"]);
+}
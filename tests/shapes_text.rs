@@ -0,0 +1,64 @@
+extern crate rust_playground;
+
+use rust_playground::shapes::text::{self, ErrorKind, ParseError};
+use rust_playground::shapes::{AnyShape, Circle, Point, Rectangle, Square, Triangle};
+
+#[test]
+fn parses_shapes_comments_and_blank_lines() {
+    let shapes = text::parse("# traits notes\nrect 30 20\n\n  circle 100.1   # radius\nsquare 10\nrectangle 1.5 2\n").unwrap();
+    assert_eq!(
+        shapes,
+        vec![
+            AnyShape::from(Rectangle::new(30.0, 20.0)),
+            Circle::new(100.1).into(),
+            Square::new(10.0).into(),
+            Rectangle::new(1.5, 2.0).into(),
+        ]
+    );
+    assert_eq!(
+        "triangle 0 0 4 0 0 -3".parse::<AnyShape>(),
+        Ok(Triangle::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, -3.0)).into())
+    );
+}
+
+#[test]
+fn printing_and_parsing_round_trips() {
+    let shapes: Vec<AnyShape> = vec![
+        Rectangle::new(0.1 + 0.2, 1e-7).into(),
+        Square::new(10.0).into(),
+        Circle::new(100.1).into(),
+        Triangle::new(Point::new(-1.5, 0.0), Point::new(1.0 / 3.0, 2.0), Point::new(5e20, -0.0)).into(),
+    ];
+    let printed = text::print(&shapes);
+    assert!(printed.starts_with("rect 0.30000000000000004 0.0000001\nsquare 10\ncircle 100.1\n"));
+    assert_eq!(text::parse(&printed).unwrap(), shapes);
+    assert_eq!(text::print(&text::parse(&printed).unwrap()), printed);
+}
+
+fn error(text: &str) -> ParseError {
+    text::parse(text).unwrap_err()
+}
+
+#[test]
+fn errors_point_at_the_line_and_column() {
+    let unknown = error("rect 1 2\n  hexagon 3\n");
+    assert_eq!((unknown.line, unknown.column), (2, 3));
+    assert_eq!(unknown.kind, ErrorKind::UnknownShape(String::from("hexagon")));
+
+    let not_a_number = error("circle ten");
+    assert_eq!((not_a_number.line, not_a_number.column), (1, 8));
+    assert_eq!(
+        not_a_number.to_string(),
+        "line 1, column 8: expected a number, found `ten`"
+    );
+
+    let missing = error("square 1\nrect 3 # no height");
+    assert_eq!((missing.line, missing.column), (2, 7));
+    assert_eq!(missing.kind, ErrorKind::MissingNumber { shape: "rect", expected: 2 });
+
+    assert_eq!(error("square 1 2").column, 10);
+    assert_eq!(error("square 1 2").kind, ErrorKind::Unexpected(String::from("2")));
+    assert_eq!(error("circle -1").kind, ErrorKind::Negative(String::from("-1")));
+    assert_eq!(error("circle inf").kind, ErrorKind::NotFinite(String::from("inf")));
+    assert_eq!("".parse::<AnyShape>().unwrap_err().kind, ErrorKind::Empty);
+}
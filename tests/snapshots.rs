@@ -0,0 +1,46 @@
+// Runs every lesson and compares what it prints with snapshots/.
+// Set UPDATE_SNAPSHOTS=1 to rewrite the snapshots after an intended change.
+
+extern crate rust_playground;
+
+use std::env;
+use std::path::Path;
+
+use rust_playground::lesson::Registry;
+use rust_playground::snapshot::{self, Mode};
+
+#[test]
+fn lessons_match_snapshots() {
+    let mode = match env::var_os("UPDATE_SNAPSHOTS") {
+        Some(_) => Mode::Update,
+        None => Mode::Check,
+    };
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshots");
+    let reports = snapshot::check_all(&dir, &Registry::builtin(), mode).unwrap();
+
+    let failures: Vec<String> = reports
+        .iter()
+        .filter(|report| report.failed())
+        .map(|report| report.to_string())
+        .collect();
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn diff_shows_changed_lines_with_context() {
+    let expected = "one\ntwo\nthree\nfour\nfive\nsix\n";
+    let actual = "one\ntwo\nthree\n4\nfive\nsix\n";
+
+    assert_eq!(
+        snapshot::diff(expected, actual),
+        "@@ line 2 @@\n two\n three\n-four\n+4\n five\n six\n"
+    );
+}
+
+#[test]
+fn diff_notices_a_missing_final_newline() {
+    assert_eq!(
+        snapshot::diff("one\n", "one"),
+        "(only the final newline differs)\n"
+    );
+}
@@ -0,0 +1,49 @@
+extern crate rust_playground;
+
+use rust_playground::shapes::svg::{self, Style, Svg};
+use rust_playground::shapes::{AnyShape, Circle, Placed, Point, Rectangle, Square, Triangle};
+
+#[test]
+fn renders_a_known_document() {
+    let shapes: Vec<AnyShape> = vec![Rectangle::new(30.0, 20.0).into(), Circle::new(5.0).into()];
+    let expected = "\
+<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"-10.5 -10.5 71 41\" width=\"71\" height=\"41\">
+  <rect x=\"0\" y=\"0\" width=\"30\" height=\"20\" fill=\"#9ecae1\" stroke=\"#3182bd\" stroke-width=\"1\"><title>rectangle, area 600</title></rect>
+  <circle cx=\"45\" cy=\"5\" r=\"5\" fill=\"#9ecae1\" stroke=\"#3182bd\" stroke-width=\"1\"><title>circle, area 78.54</title></circle>
+</svg>
+";
+    assert_eq!(svg::render(&shapes), expected);
+    // the same shapes always give the same document.
+    assert_eq!(svg::render(&shapes), svg::render(&shapes.clone()));
+}
+
+#[test]
+fn view_box_covers_every_shape() {
+    let drawing = Svg::new()
+        .margin(0.0)
+        .add(Placed::new(Circle::new(10.0), Point::new(0.0, 0.0)), Style::new("red", "none", 0.0))
+        .add(Placed::new(Square::new(5.0), Point::new(20.0, 30.0)), Style::outline("black"))
+        .add(
+            Placed::new(
+                Triangle::new(Point::new(0.0, 0.0), Point::new(-4.0, 3.0), Point::new(2.0, -50.0)),
+                Point::new(0.0, 0.0),
+            ),
+            Style::new("none", "none", 0.0),
+        );
+    assert_eq!(drawing.view_box(), (-10.0, -50.0, 35.5, 85.5));
+    let document = drawing.render();
+    assert!(document.contains("<polygon points=\"0,0 -4,3 2,-50\""));
+    assert!(document.contains("fill=\"none\" stroke=\"black\""));
+}
+
+#[test]
+fn styles_are_escaped_and_numbers_kept_short() {
+    let document = Svg::new()
+        .add(
+            Placed::new(Rectangle::new(1.0 / 3.0, 2.0), Point::new(-0.0001, 0.5)),
+            Style::new("url(\"#a\")", "a&b", 0.25),
+        )
+        .render();
+    assert!(document.contains("<rect x=\"0\" y=\"0.5\" width=\"0.333\" height=\"2\""));
+    assert!(document.contains("fill=\"url(&quot;#a&quot;)\" stroke=\"a&amp;b\" stroke-width=\"0.25\""));
+}
@@ -0,0 +1,66 @@
+extern crate rust_playground;
+
+use rust_playground::lesson::Registry;
+use rust_playground::tracked::{Event, Log};
+
+#[test]
+fn locals_drop_in_reverse_order() {
+    let log = Log::new();
+    {
+        let _first = log.track("first", 1);
+        let _second = log.track("second", 2);
+        {
+            let _inner = log.track("inner", 3);
+        }
+        log.note("inner scope ended");
+    }
+    assert_eq!(log.dropped(), ["inner", "second", "first"]);
+    assert_eq!(
+        log.to_string(),
+        "created first\ncreated second\ncreated inner\ndropped inner\n-- inner scope ended\ndropped second\ndropped first\n"
+    );
+}
+
+#[test]
+fn clones_are_labelled_and_dropped_separately() {
+    let log = Log::new();
+    let original = log.track("s", String::from("hello"));
+    let mut copy = original.clone();
+    copy.push_str(" world");
+    let second = original.clone();
+
+    assert_eq!(*original, "hello");
+    assert_eq!(*copy, "hello world");
+    assert_eq!(second.label(), "s clone 2");
+    drop(copy);
+    assert_eq!(log.alive(), ["s", "s clone 2"]);
+    assert_eq!(
+        log.events()[1],
+        Event::Cloned {
+            original: String::from("s"),
+            clone: String::from("s clone 1"),
+        }
+    );
+}
+
+#[test]
+fn moving_does_not_drop() {
+    let log = Log::new();
+    let values = vec![log.track("a", 'a'), log.track("b", 'b')];
+    let moved = values;
+    assert!(log.dropped().is_empty());
+    drop(moved);
+    // a Vec drops its items front to back.
+    assert_eq!(log.dropped(), ["a", "b"]);
+}
+
+#[test]
+fn ownership_lesson_drops_everything_it_tracks() {
+    let output = Registry::builtin().find("intro_1::ownership").unwrap().capture();
+    let dropped: Vec<&str> = output
+        .lines()
+        .skip_while(|line| *line != "Tracked example start.")
+        .filter_map(|line| line.strip_prefix("dropped "))
+        .collect();
+    assert_eq!(dropped, ["v4", "v5 clone 1", "v5"]);
+}
@@ -0,0 +1,134 @@
+extern crate rust_playground;
+
+use std::f64::consts::PI;
+
+use rust_playground::shapes::polygon::{Polygon, PolygonError};
+use rust_playground::shapes::transform::{Transform, Transformed, Vector2, ELLIPSE_CORNERS};
+use rust_playground::shapes::{AnyShape, Circle, Placed, Point, Rectangle, Shape, Square, Triangle};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+fn close_points(a: Point, b: Point) -> bool {
+    close(a.x, b.x) && close(a.y, b.y)
+}
+
+#[test]
+fn point_and_vector_arithmetic() {
+    let step = Vector2::new(3.0, 4.0);
+    assert_eq!(step + step, Vector2::new(6.0, 8.0));
+    assert_eq!(step - Vector2::new(1.0, 1.0), Vector2::new(2.0, 3.0));
+    assert_eq!(step * 2.0, 2.0 * step);
+    assert_eq!(-step, Vector2::new(-3.0, -4.0));
+    assert_eq!(step.length(), 5.0);
+
+    let mut total = Vector2::default();
+    total += step;
+    total += step;
+    assert_eq!(total, Vector2::new(6.0, 8.0));
+
+    let mut at = Point::new(1.0, 1.0) + step;
+    assert_eq!(at, Point::new(4.0, 5.0));
+    assert_eq!(at - Point::new(1.0, 1.0), step);
+    assert_eq!(at - step, Point::new(1.0, 1.0));
+    at += -step;
+    assert_eq!(at, Point::new(1.0, 1.0));
+}
+
+#[test]
+fn composes_transforms() {
+    let p = Point::new(1.0, 2.0);
+    assert_eq!(Transform::identity().apply(p), p);
+    assert_eq!(Transform::translate(Vector2::new(5.0, -1.0)).apply(p), Point::new(6.0, 1.0));
+    assert_eq!(Transform::scale(2.0, 3.0).apply(p), Point::new(2.0, 6.0));
+    // a quarter turn takes the x axis to the y axis.
+    assert!(close_points(Transform::rotate(PI / 2.0).apply(Point::new(1.0, 0.0)), Point::new(0.0, 1.0)));
+
+    // scale, then move: the order matters.
+    let scale = Transform::scale(2.0, 2.0);
+    let step = Transform::translate(Vector2::new(1.0, 0.0));
+    assert_eq!(scale.then(step).apply(p), Point::new(3.0, 4.0));
+    assert_eq!(step.then(scale).apply(p), Point::new(4.0, 4.0));
+    // moving does not change steps.
+    assert_eq!(scale.then(step).apply_vector(Vector2::new(1.0, 1.0)), Vector2::new(2.0, 2.0));
+}
+
+#[test]
+fn transforms_every_kind_of_shape() {
+    let at = Point::new(10.0, 0.0);
+    let quarter = Transform::rotate(PI / 2.0);
+
+    // a quarter turn keeps a rectangle lined up with the axes.
+    match quarter.apply_to(&Placed::new(Rectangle::new(30.0, 20.0), at)).unwrap() {
+        Transformed::Shape(Placed {
+            shape: AnyShape::Rectangle(r),
+            at,
+        }) => {
+            assert!(close(r.width, 20.0) && close(r.height, 30.0));
+            assert!(close_points(at, Point::new(-20.0, 10.0)));
+        }
+        other => panic!("expected a rectangle, got {:?}", other),
+    }
+    // any other turn makes a polygon with the same area.
+    let turned = Transform::rotate(PI / 6.0).apply_to(&Placed::new(Rectangle::new(30.0, 20.0), at)).unwrap();
+    assert_eq!(turned.kind(), "polygon");
+    assert!(close(turned.area(), 600.0) && close(turned.perimeter(), 100.0));
+
+    // a stretched square is a rectangle.
+    let stretched = Transform::scale(2.0, 1.0).apply_to(&Placed::new(Square::new(5.0), at)).unwrap();
+    assert_eq!((stretched.kind(), stretched.area()), ("rectangle", 50.0));
+
+    // circles stay circles unless stretched.
+    let moved = Transform::scale(3.0, 3.0).then(quarter).apply_to(&Placed::new(Circle::new(1.0), at)).unwrap();
+    match moved {
+        Transformed::Shape(placed) => {
+            assert_eq!(placed.shape.kind(), "circle");
+            assert!(close(placed.shape.area(), 9.0 * PI));
+            assert!(close_points(placed.at, Point::new(0.0, 30.0)));
+        }
+        other => panic!("expected a circle, got {:?}", other),
+    }
+    match Transform::scale(2.0, 1.0).apply_to(&Placed::new(Circle::new(1.0), at)).unwrap() {
+        Transformed::Polygon(polygon) => {
+            assert_eq!(polygon.points().len(), ELLIPSE_CORNERS);
+            // close to the ellipse's 2π.
+            assert!((polygon.area() - 2.0 * PI).abs() < 0.02);
+        }
+        other => panic!("expected a polygon, got {:?}", other),
+    }
+
+    // triangles keep their kind, and their area scales with the transform.
+    let triangle = Triangle::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 3.0));
+    let moved = Transform::scale(2.0, 1.0).then(quarter).apply_to(&Placed::new(triangle, at)).unwrap();
+    assert_eq!(moved.kind(), "triangle");
+    assert!(close(moved.area(), 12.0));
+
+    let polygon = Polygon::new(vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(0.0, 1.0)]).unwrap();
+    assert!(close(Transform::scale(2.0, 2.0).apply_to_polygon(&polygon).unwrap().area(), 2.0));
+    // squashing a shape flat leaves no polygon.
+    let arrow = Polygon::new(vec![Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(1.0, 1.0), Point::new(0.0, 2.0)]);
+    assert_eq!(
+        Transform::scale(1.0, 0.0).then(quarter).apply_to_polygon(&arrow.unwrap()),
+        Err(PolygonError::Collinear)
+    );
+}
+
+#[test]
+fn squashing_flat_fails_for_every_kind_of_shape() {
+    let at = Point::new(1.0, 2.0);
+    let shapes: Vec<AnyShape> = vec![
+        Rectangle::new(3.0, 2.0).into(),
+        Square::new(2.0).into(),
+        Circle::new(1.0).into(),
+        Triangle::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 3.0)).into(),
+    ];
+    let turned = Transform::rotate(PI / 6.0);
+    for flat in &[Transform::scale(0.0, 1.0), Transform::scale(2.0, 0.0).then(turned), Transform::scale(0.0, 0.0)] {
+        assert_eq!(flat.determinant(), 0.0);
+        for shape in &shapes {
+            assert_eq!(flat.apply_to(&Placed::new(*shape, at)), Err(PolygonError::Collinear), "{:?}", shape);
+        }
+    }
+    assert!(close(turned.determinant(), 1.0));
+}